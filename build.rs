@@ -0,0 +1,107 @@
+use std::{env, fs, path::PathBuf};
+
+/// (chip, flash KiB, ram KiB)
+///
+/// RAM is the SRAM block starting at `0x2000_0000`, SRAMX (code RAM) is not included.
+const MEMORY: &[(&str, u32, u32)] = &[
+    ("mcxa132", 64, 16),
+    ("mcxa133", 128, 24),
+    ("mcxa142", 64, 16),
+    ("mcxa143", 128, 24),
+    ("mcxa152", 64, 16),
+    ("mcxa153", 128, 24),
+    ("mcxa144", 256, 56),
+    ("mcxa145", 512, 88),
+    ("mcxa146", 1024, 120),
+    ("mcxa154", 256, 56),
+    ("mcxa155", 512, 88),
+    ("mcxa156", 1024, 120),
+    ("mcxa255", 512, 120),
+    ("mcxa256", 1024, 120),
+    ("mcxa345", 512, 120),
+    ("mcxa346", 1024, 120),
+    ("mcxn235", 512, 160),
+    ("mcxn236", 1024, 224),
+    ("mcxn546", 1024, 320),
+    ("mcxn547", 2048, 320),
+    ("mcxn946", 1024, 320),
+    ("mcxn947", 2048, 320),
+];
+
+/// Pin function tables in `pins/<family>.csv`, see [`pin_functions`].
+const FAMILIES: &[&str] = &["mcxa0", "mcxa1", "mcxa2", "mcxn0", "mcxn1"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    if let Some(family) = FAMILIES
+        .iter()
+        .find(|f| env::var(format!("CARGO_FEATURE_{}", f.to_uppercase())).is_ok())
+    {
+        let table = format!("pins/{family}.csv");
+        println!("cargo:rerun-if-changed={table}");
+        let csv = fs::read_to_string(&table).unwrap();
+        fs::write(out.join("pin_functions.rs"), pin_functions(&table, &csv)).unwrap();
+    }
+
+    let chips: Vec<_> = MEMORY
+        .iter()
+        .filter(|(chip, _, _)| env::var(format!("CARGO_FEATURE_{}", chip.to_uppercase())).is_ok())
+        .collect();
+    if chips.len() > 1 {
+        panic!("Should select only 1 chip at the same time");
+    }
+    let Some((_, flash, ram)) = chips.first() else {
+        return;
+    };
+
+    // Linker searches the working directory first, so a `memory.x` in the
+    // application crate overrides this one.
+    fs::write(
+        out.join("memory.x"),
+        format!(
+            "MEMORY\n{{\n    FLASH : ORIGIN = 0x00000000, LENGTH = {flash}K\n    RAM   : ORIGIN = 0x20000000, LENGTH = {ram}K\n}}\n"
+        ),
+    )
+    .unwrap();
+    println!("cargo:rustc-link-search={}", out.display());
+}
+
+/// Generate pin trait impls from a pin function table.
+///
+/// Each line is `port,pin,function,instance,signal,mux`, `#` starts a comment.
+/// `function` selects the macro in `src/port/<function>.rs`, unused columns are left empty.
+/// See `pins/README.md` for what the tables cover.
+fn pin_functions(table: &str, csv: &str) -> String {
+    let mut code = String::new();
+    for (n, line) in csv.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let cols: Vec<_> = line.split(',').map(str::trim).collect();
+        let [port, pin, function, instance, signal, mux] = cols[..] else {
+            panic!("{table}:{}: expect 6 columns", n + 1);
+        };
+        let pin = format!("PortPin<{port}, {pin}>");
+        let item = match function {
+            "LPUART" => {
+                format!("lpuart!(pin: {pin}, module: U{instance}, signal: {signal}, mux: {mux});")
+            }
+            "SCG" => format!("scg!(pin: {pin}, module: U{instance}, signal: {signal});"),
+            "CLKOUT" => format!("crate::port::clkout::clkout!(pin: {pin}, mux: {mux});"),
+            "CTIMER" => format!(
+                "crate::port::ctimer::ctimer!(pin: {pin}, module: U{instance}, signal: {signal}, mux: {mux});"
+            ),
+            // signal is the INPUTMUX select value of the CT_INPn input
+            "CTIMER_INP" => format!(
+                "crate::port::ctimer::ctimer!(pin: {pin}, input: {signal}, mux: {mux});"
+            ),
+            _ => panic!("{table}:{}: unknown function {function}", n + 1),
+        };
+        code.push_str(&item);
+        code.push('\n');
+    }
+    code
+}
@@ -0,0 +1,22 @@
+#![no_std]
+#![no_main]
+
+extern crate panic_halt;
+
+use eio06::Write;
+use mcx_hal::bsp::Board;
+
+#[cortex_m_rt::entry]
+fn main() -> ! {
+    let mut board = Board::take().unwrap();
+
+    writeln!(board.debug_uart, "Hello World!").unwrap();
+
+    loop {
+        if board.button.is_clear() {
+            board.led.clear();
+        } else {
+            board.led.set();
+        }
+    }
+}
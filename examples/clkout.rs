@@ -22,14 +22,10 @@ fn main() -> ! {
     };
     scg.freeze(&cfg).unwrap();
 
-    let port4 = Port4::new(unsafe { pac::port::PORT4::instance() });
-    let mut clkout = port4.p2;
-    clkout.floating();
-    clkout.analog(false);
-    clkout.set_mux(1);
+    let spll_freq = cfg.spll.unwrap().freq(FIRC::default().freq());
 
-    setup_clkout_clock_source(ClkOutSource::SPll);
-    setup_clkout_divider(Some(8));
+    let port4 = Port4::new(unsafe { pac::port::PORT4::instance() });
+    let _clkout = ClkOut::new(port4.p2, ClkOutSource::SPll, spll_freq, 8);
 
     loop {}
 }
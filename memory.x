@@ -1,5 +0,0 @@
-MEMORY
-{
-    FLASH : ORIGIN = 0x00000000, LENGTH = 64K
-    RAM   : ORIGIN = 0x20000000, LENGTH = 16K
-}
\ No newline at end of file
@@ -0,0 +1,154 @@
+//! FRDM-MCXA153 and FRDM-MCXA156, both boards share the same pinout.
+
+use crate::{
+    gpio::{Input, Output, GPIO},
+    lpuart::{BaudRate, Direction, LpUart, Pins},
+    pac,
+    port::{Port, Port0, Port1, Port3, PortPin},
+    scg::{Config, SCG},
+    syscon::{setup_lpuart0_clock_source, setup_lpuart0_divider, MRCCClockSource},
+};
+
+/// FRO_12M, LPUART0 functional clock.
+const DEBUG_UART_CLOCK: u32 = 12_000_000;
+
+/// Debug UART, LPUART0 on MCU-Link VCOM.
+pub type DebugUart = LpUart<0, Pins<PortPin<0, 3>, PortPin<0, 2>>>;
+
+super::unused_pins!(
+    /// PORT0 pins not used by the board.
+    Port0Rest, Port0<0> {
+        used: (p2: 2, p3: 3, p6: 6),
+        p0: 0, p1: 1, p16: 16, p17: 17,
+        #[cfg(feature = "mcxa1")] p18: 18,
+        #[cfg(feature = "mcxa1")] p19: 19,
+        #[cfg(feature = "mcxa1")] p20: 20,
+        #[cfg(feature = "mcxa1")] p21: 21,
+        #[cfg(feature = "mcxa1")] p22: 22,
+        #[cfg(feature = "mcxa1")] p23: 23,
+    }
+);
+super::unused_pins!(
+    /// PORT1 pins not used by the board.
+    Port1Rest, Port1<1> {
+        used: (p7: 7),
+        p0: 0, p1: 1, p2: 2, p3: 3, p4: 4, p5: 5, p6: 6, p8: 8, p9: 9, p10: 10, p11: 11,
+        p12: 12, p13: 13,
+        #[cfg(feature = "mcxa1")] p14: 14,
+        #[cfg(feature = "mcxa1")] p15: 15,
+        p29: 29, p30: 30, p31: 31,
+    }
+);
+super::unused_pins!(
+    /// PORT3 pins not used by the board.
+    Port3Rest, Port3<3> {
+        used: (p0: 0, p12: 12, p13: 13),
+        p1: 1,
+        #[cfg(feature = "mcxa1")] p2: 2,
+        p6: 6, p7: 7, p8: 8, p9: 9, p10: 10, p11: 11, p14: 14, p15: 15,
+        #[cfg(feature = "mcxa1")] p16: 16,
+        #[cfg(feature = "mcxa1")] p17: 17,
+        #[cfg(feature = "mcxa1")] p18: 18,
+        #[cfg(feature = "mcxa1")] p19: 19,
+        #[cfg(feature = "mcxa1")] p20: 20,
+        #[cfg(feature = "mcxa1")] p21: 21,
+        #[cfg(feature = "mcxa1")] p22: 22,
+        p27: 27, p28: 28, p29: 29, p30: 30, p31: 31,
+    }
+);
+
+/// FRDM-MCXA153 / FRDM-MCXA156 board.
+pub struct Board {
+    pub scg: SCG<()>,
+    pub gpio0: GPIO<0>,
+    pub gpio1: GPIO<1>,
+    pub gpio3: GPIO<3>,
+
+    /// User LED, RGB LED red, active low.
+    pub led: Output<PortPin<3, 12>>,
+    /// RGB LED green, active low.
+    pub led_green: Output<PortPin<3, 13>>,
+    /// RGB LED blue, active low.
+    pub led_blue: Output<PortPin<3, 0>>,
+
+    /// User button SW2, low when pressed.
+    pub button: Input<PortPin<1, 7>>,
+    /// SW3 wakeup button, low when pressed.
+    pub sw3: Input<PortPin<0, 6>>,
+
+    pub debug_uart: DebugUart,
+
+    /// Unused PORT0 pins, PORT2 is left untouched.
+    pub port0: Port0Rest,
+    /// Unused PORT1 pins.
+    pub port1: Port1Rest,
+    /// Unused PORT3 pins.
+    pub port3: Port3Rest,
+}
+
+impl Board {
+    /// Take the board, returns `None` if it's already taken or clock setup fails.
+    ///
+    /// Clocks are set up with default [`Config`], LEDs are off and debug UART
+    /// is enabled at [`super::DEBUG_UART_BAUD`].
+    pub fn take() -> Option<Self> {
+        if !super::take() {
+            return None;
+        }
+
+        let mut scg = SCG::without_pins(unsafe { pac::scg::SCG0::instance() });
+        if scg.freeze(&Config::default()).is_err() {
+            super::untake();
+            return None;
+        }
+
+        let (port0, (p0_2, p0_3, p0_6)) =
+            Port0Rest::split(Port0::new(unsafe { pac::port::PORT0::instance() }));
+        let (port1, p1_7) = Port1Rest::split(Port1::new(unsafe { pac::port::PORT1::instance() }));
+        let (port3, (p3_0, p3_12, p3_13)) =
+            Port3Rest::split(Port3::new(unsafe { pac::port::PORT3::instance() }));
+        let mut gpio0 = GPIO::new(unsafe { pac::gpio::GPIO0::instance() });
+        let mut gpio1 = GPIO::new(unsafe { pac::gpio::GPIO1::instance() });
+        let mut gpio3 = GPIO::new(unsafe { pac::gpio::GPIO3::instance() });
+
+        let led = gpio3.output(p3_12);
+        let led_green = gpio3.output(p3_13);
+        let led_blue = gpio3.output(p3_0);
+        led.set();
+        led_green.set();
+        led_blue.set();
+
+        let mut button = gpio1.input(p1_7);
+        button.mut_pin().analog(false);
+        let mut sw3 = gpio0.input(p0_6);
+        sw3.mut_pin().analog(false);
+
+        setup_lpuart0_clock_source(MRCCClockSource::Fro12M);
+        setup_lpuart0_divider(Some(0));
+        let mut debug_uart = LpUart::new(
+            unsafe { pac::lpuart::LPUART0::instance() },
+            Pins { tx: p0_3, rx: p0_2 },
+        );
+        debug_uart.configure(|i| {
+            i.set_baud(&BaudRate::new(DEBUG_UART_CLOCK, super::DEBUG_UART_BAUD).unwrap());
+        });
+        debug_uart.set_enable(Direction::TX, true);
+        debug_uart.set_enable(Direction::RX, true);
+
+        Some(Self {
+            scg,
+            gpio0,
+            gpio1,
+            gpio3,
+            led,
+            led_green,
+            led_blue,
+            button,
+            sw3,
+            debug_uart,
+            port0,
+            port1,
+            port3,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+//! FRDM-MCXA346.
+
+use crate::{
+    gpio::{Input, Output, GPIO},
+    lpuart::{BaudRate, Direction, LpUart, Pins},
+    pac,
+    port::{Port, Port1, Port2, Port3, PortPin},
+    scg::{Config, SCG},
+    syscon::{
+        setup_fro_hf_divider, setup_lpuart2_clock_source, setup_lpuart2_divider, MRCCClockSource,
+    },
+};
+
+/// Debug UART, LPUART2 on MCU-Link VCOM.
+pub type DebugUart = LpUart<2, Pins<PortPin<2, 2>, PortPin<2, 3>>>;
+
+super::unused_pins!(
+    /// PORT1 pins not used by the board.
+    Port1Rest, Port1<1> {
+        used: (p7: 7),
+        p0: 0, p1: 1, p2: 2, p3: 3, p4: 4, p5: 5, p6: 6, p8: 8, p9: 9, p10: 10, p11: 11,
+        p12: 12, p13: 13, p14: 14, p15: 15, p16: 16, p17: 17, p18: 18, p19: 19,
+        p29: 29, p30: 30, p31: 31,
+    }
+);
+super::unused_pins!(
+    /// PORT2 pins not used by the board.
+    Port2Rest, Port2<2> {
+        used: (p2: 2, p3: 3),
+        p0: 0, p1: 1, p4: 4, p5: 5, p6: 6, p7: 7, p8: 8, p9: 9, p10: 10, p11: 11, p12: 12,
+        p13: 13, p14: 14, p15: 15, p16: 16, p17: 17, p18: 18, p19: 19, p20: 20, p21: 21,
+        p22: 22, p23: 23, p24: 24, p25: 25, p26: 26,
+    }
+);
+super::unused_pins!(
+    /// PORT3 pins not used by the board.
+    Port3Rest, Port3<3> {
+        used: (p18: 18, p19: 19, p21: 21),
+        p0: 0, p1: 1, p2: 2, p3: 3, p4: 4, p5: 5, p6: 6, p7: 7, p8: 8, p9: 9, p10: 10,
+        p11: 11, p12: 12, p13: 13, p14: 14, p15: 15, p16: 16, p17: 17, p20: 20, p22: 22,
+        p23: 23, p24: 24, p25: 25, p26: 26, p27: 27, p28: 28, p29: 29, p30: 30, p31: 31,
+    }
+);
+
+/// FRDM-MCXA346 board.
+pub struct Board {
+    pub scg: SCG<()>,
+    pub gpio1: GPIO<1>,
+    pub gpio3: GPIO<3>,
+
+    /// User LED, RGB LED red, active low.
+    pub led: Output<PortPin<3, 18>>,
+    /// RGB LED green, active low.
+    pub led_green: Output<PortPin<3, 19>>,
+    /// RGB LED blue, active low.
+    pub led_blue: Output<PortPin<3, 21>>,
+    /// User button, low when pressed.
+    pub button: Input<PortPin<1, 7>>,
+
+    pub debug_uart: DebugUart,
+
+    /// Unused PORT1 pins, PORT0 and PORT4 are left untouched.
+    pub port1: Port1Rest,
+    /// Unused PORT2 pins.
+    pub port2: Port2Rest,
+    /// Unused PORT3 pins.
+    pub port3: Port3Rest,
+}
+
+impl Board {
+    /// Take the board, returns `None` if it's already taken or clock setup fails.
+    ///
+    /// Clocks are set up with default [`Config`], LEDs are off and debug UART
+    /// is enabled at [`super::DEBUG_UART_BAUD`], clocked from FRO_HF.
+    pub fn take() -> Option<Self> {
+        if !super::take() {
+            return None;
+        }
+
+        let config = Config::default();
+        let mut scg = SCG::without_pins(unsafe { pac::scg::SCG0::instance() });
+        if scg.freeze(&config).is_err() {
+            super::untake();
+            return None;
+        }
+
+        let (port1, p1_7) = Port1Rest::split(Port1::new(unsafe { pac::port::PORT1::instance() }));
+        let (port2, (p2_2, p2_3)) =
+            Port2Rest::split(Port2::new(unsafe { pac::port::PORT2::instance() }));
+        let (port3, (p3_18, p3_19, p3_21)) =
+            Port3Rest::split(Port3::new(unsafe { pac::port::PORT3::instance() }));
+        let mut gpio1 = GPIO::new(unsafe { pac::gpio::GPIO1::instance() });
+        let mut gpio3 = GPIO::new(unsafe { pac::gpio::GPIO3::instance() });
+
+        let led = gpio3.output(p3_18);
+        let led_green = gpio3.output(p3_19);
+        let led_blue = gpio3.output(p3_21);
+        led.set();
+        led_green.set();
+        led_blue.set();
+        let mut button = gpio1.input(p1_7);
+        button.mut_pin().analog(false);
+
+        setup_fro_hf_divider(Some(0));
+        setup_lpuart2_clock_source(MRCCClockSource::FroHfDiv);
+        setup_lpuart2_divider(Some(0));
+        let mut debug_uart = LpUart::new(
+            unsafe { pac::lpuart::LPUART2::instance() },
+            Pins { tx: p2_2, rx: p2_3 },
+        );
+        let firc = config.firc.unwrap_or_default();
+        debug_uart.configure(|i| {
+            i.set_baud(&BaudRate::new(firc.freq(), super::DEBUG_UART_BAUD).unwrap());
+        });
+        debug_uart.set_enable(Direction::TX, true);
+        debug_uart.set_enable(Direction::RX, true);
+
+        Some(Self {
+            scg,
+            gpio1,
+            gpio3,
+            led,
+            led_green,
+            led_blue,
+            button,
+            debug_uart,
+            port1,
+            port2,
+            port3,
+        })
+    }
+}
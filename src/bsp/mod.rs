@@ -0,0 +1,84 @@
+//! Board support for NXP FRDM development boards.
+//!
+//! Enable `bsp` feature together with the chip feature of the board:
+//! - `mcxa153`: FRDM-MCXA153
+//! - `mcxa156`: FRDM-MCXA156
+//! - `mcxa346`: FRDM-MCXA346
+//!
+//! # Example
+//!
+//! ```rust
+//! let mut board = Board::take().unwrap();
+//! writeln!(board.debug_uart, "Hello World!").unwrap();
+//! ```
+//!
+//! Every board has a user LED `led` and a user button `button`, other LEDs
+//! and buttons are named after their board label. Pins the board doesn't use
+//! are handed out in `portN` fields together with the port's
+//! [`crate::port::PortGlobal`].
+
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(any(feature = "mcxa153", feature = "mcxa156"))] {
+        mod frdm_mcxa15x;
+        pub use frdm_mcxa15x::*;
+    } else if #[cfg(feature = "mcxa346")] {
+        mod frdm_mcxa346;
+        pub use frdm_mcxa346::*;
+    } else {
+        compile_error!("`bsp` supports FRDM-MCXA153, FRDM-MCXA156 and FRDM-MCXA346 only");
+    }
+}
+
+/// Debug UART baud rate, the MCU-Link VCOM default.
+pub const DEBUG_UART_BAUD: u32 = 115200;
+
+/// `true` once the board has been taken.
+static TAKEN: core::sync::atomic::AtomicBool = core::sync::atomic::AtomicBool::new(false);
+
+fn take() -> bool {
+    !TAKEN.swap(true, core::sync::atomic::Ordering::AcqRel)
+}
+
+/// Undo [`take`] when board setup fails, so it can be retried.
+fn untake() {
+    TAKEN.store(false, core::sync::atomic::Ordering::Release);
+}
+
+/// Define a struct holding the pins of a port left over by the board and the
+/// port's [`crate::port::PortGlobal`].
+///
+/// `split` returns it together with the `used` pins.
+macro_rules! unused_pins {
+    ($(#[$meta:meta])* $name:ident, $port:ident<$n:literal> {
+        used: ($($used:ident: $u:literal),+ $(,)?),
+        $($(#[$pin_meta:meta])* $pin:ident: $k:literal),+ $(,)?
+    }) => {
+        $(#[$meta])*
+        pub struct $name {
+            pub global: crate::port::PortGlobal<$n>,
+            $($(#[$pin_meta])* pub $pin: crate::port::PortPin<$n, $k>,)+
+        }
+
+        impl $name {
+            #[allow(unused_parens)]
+            fn split(
+                port: crate::port::$port,
+            ) -> (Self, ($(crate::port::PortPin<$n, $u>),+)) {
+                let crate::port::$port {
+                    global,
+                    $($used,)+
+                    $($(#[$pin_meta])* $pin,)+
+                    ..
+                } = port;
+                let rest = Self {
+                    global,
+                    $($(#[$pin_meta])* $pin,)+
+                };
+                (rest, ($($used),+))
+            }
+        }
+    };
+}
+use unused_pins;
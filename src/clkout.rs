@@ -0,0 +1,70 @@
+//! Clock Output (CLKOUT)
+//!
+//! # Example
+//!
+//! ```rust
+//! let port4 = Port4::new(unsafe { pac::port::PORT4::instance() });
+//! // output SPLL / 9
+//! let clkout = ClkOut::new(port4.p2, ClkOutSource::SPll, spll_freq, 8);
+//! let freq = clkout.freq();
+//! ```
+
+use crate::{
+    port::clkout::{prepare, Pin},
+    syscon::{setup_clkout_clock_source, setup_clkout_divider, ClkOutSource},
+};
+
+/// CLKOUT driver.
+pub struct ClkOut<P> {
+    pin: P,
+    source: ClkOutSource,
+    source_freq: u32,
+    divider: u8,
+}
+
+impl<P: Pin> ClkOut<P> {
+    /// Output `source` clock on `pin`, divided by `divider + 1`.
+    ///
+    /// `source_freq` is the frequency of `source` clock, it is only used to report
+    /// the output frequency.
+    pub fn new(mut pin: P, source: ClkOutSource, source_freq: u32, divider: u8) -> Self {
+        prepare(&mut pin);
+
+        setup_clkout_clock_source(source);
+        setup_clkout_divider(Some(divider));
+
+        Self {
+            pin,
+            source,
+            source_freq,
+            divider,
+        }
+    }
+
+    /// Change CLKOUT divider, output is `source` clock divided by `divider + 1`.
+    pub fn set_divider(&mut self, divider: u8) {
+        setup_clkout_divider(Some(divider));
+        self.divider = divider;
+    }
+
+    /// Return current CLKOUT source.
+    pub fn source(&self) -> ClkOutSource {
+        self.source
+    }
+
+    /// Return CLKOUT output frequency.
+    pub fn freq(&self) -> u32 {
+        match self.source {
+            ClkOutSource::NoClock => 0,
+            _ => self.source_freq / (self.divider as u32 + 1),
+        }
+    }
+
+    /// Stop CLKOUT and release the pin, the pin is restored to GPIO function.
+    pub fn release(mut self) -> P {
+        setup_clkout_divider(None);
+        setup_clkout_clock_source(ClkOutSource::NoClock);
+        self.pin.set_mux(0);
+        self.pin
+    }
+}
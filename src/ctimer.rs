@@ -0,0 +1,564 @@
+//! Standard Counter / Timer (CTIMER)
+//!
+//! The timer counter (TC) increments every `prescaler + 1` functional clock
+//! cycles. Match channel 3 sets the period in periodic and PWM mode.
+//!
+//! CTIMER functional clock is selected in MRCC (MCX A) or SYSCON (MCX N), the
+//! driver only needs its frequency.
+//!
+//! # Example
+//!
+//! ```rust
+//! let mut ctimer = CTimer::new(unsafe { pac::ctimer::CTIMER0::instance() }, 12_000_000);
+//!
+//! // 1 kHz PWM, `mat0` is a pin with CTIMER0 MAT0 function
+//! ctimer.start();
+//! let pwm = ctimer.pwm_channels(12_000);
+//! let mut ch0 = pwm.ch0.with_pin(mat0);
+//! ch0.set_duty_cycle_percent(25).unwrap();
+//! let mat0 = ch0.release();
+//!
+//! // capture rising edges of `inp0`, a pin with CT_INP0 function, on channel 0
+//! let inp0 = ctimer.capture_pin(Channel::Ch0, inp0);
+//! ctimer.configure_capture(Channel::Ch0, Some(CaptureEdge::Rising), true);
+//!
+//! // blocking delay, e.g. when SysTick is owned by RTIC
+//! let mut delay = ctimer.into_delay();
+//! delay.delay_ms(10);
+//!
+//! // 10 ms count down timer
+//! let mut timer = delay.release().into_count_down();
+//! timer.start_us(10_000);
+//! nb::block!(timer.wait()).unwrap();
+//! ```
+
+use crate::{
+    consts::Const,
+    pac::{ctimer::Instance, inputmux::INPUTMUX0},
+    port::ctimer::{prepare, CapturePin, Pin, Signal, MAT0, MAT1, MAT2},
+    syscon::PeripheralGate,
+};
+use core::{convert::Infallible, marker::PhantomData};
+
+/// CTIMER match or capture channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Channel {
+    Ch0 = 0,
+    Ch1 = 1,
+    Ch2 = 2,
+    Ch3 = 3,
+}
+
+/// Match output action on match.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatchAction {
+    #[default]
+    DoNothing = 0,
+    Clear = 1,
+    Set = 2,
+    Toggle = 3,
+}
+
+/// Capture edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaptureEdge {
+    Rising,
+    Falling,
+    Both,
+}
+
+bitflags::bitflags! {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct CTimerInterrupt: u32 {
+        const MATCH0 = 1 << 0;
+        const MATCH1 = 1 << 1;
+        const MATCH2 = 1 << 2;
+        const MATCH3 = 1 << 3;
+        const CAPTURE0 = 1 << 4;
+        const CAPTURE1 = 1 << 5;
+        const CAPTURE2 = 1 << 6;
+        const CAPTURE3 = 1 << 7;
+    }
+}
+
+/// INPUTMUX CTIMERn_CAPx register offset of each CTIMER, same on MCX A and N.
+const CAPTURE_INPUT_OFFSET: [usize; 5] = [0x20, 0x40, 0x60, 0x1a0, 0x1c0];
+
+/// CTIMER driver.
+pub struct CTimer<const N: u8> {
+    ctimer: Instance<N>,
+    clock: u32,
+}
+
+impl<const N: u8> CTimer<N> {
+    /// Create a new CTIMER driver, `clock` is the CTIMER functional clock frequency.
+    pub fn new(mut ctimer: Instance<N>, clock: u32) -> Self
+    where
+        Instance<N>: PeripheralGate,
+    {
+        ctimer.acquire();
+
+        // Capture input selection lives in INPUTMUX
+        unsafe { INPUTMUX0::instance() }.acquire();
+
+        let ret = Self { ctimer, clock };
+        let regs = ret.regs();
+        regs.TCR().write(|r| r.set_CRST(true));
+        regs.PR().write_value(0);
+        regs.MCR().write(|r| r.0 = 0);
+        regs.CCR().write(|r| r.0 = 0);
+        regs.EMR().write(|r| r.0 = 0);
+        regs.PWMC().write(|r| r.0 = 0);
+        regs.IR().write(|r| r.0 = CTimerInterrupt::all().bits());
+        ret
+    }
+
+    /// Stop CTIMER and release instance, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> Instance<N>
+    where
+        Instance<N>: PeripheralGate,
+    {
+        self.stop();
+        unsafe { INPUTMUX0::instance() }.release();
+        self.ctimer.release();
+        self.ctimer
+    }
+
+    /// CTIMER functional clock frequency.
+    pub const fn clock(&self) -> u32 {
+        self.clock
+    }
+
+    /// Set prescaler, the counter increments every `prescaler + 1` clock cycles.
+    pub fn set_prescaler(&mut self, prescaler: u32) {
+        self.regs().PR().write_value(prescaler);
+    }
+
+    /// Counter tick frequency.
+    pub fn tick_freq(&self) -> u32 {
+        self.clock / (self.regs().PR().read() + 1)
+    }
+
+    /// Start counting.
+    pub fn start(&mut self) {
+        self.regs().TCR().write(|r| r.set_CEN(true));
+    }
+
+    /// Stop counting.
+    pub fn stop(&mut self) {
+        self.regs().TCR().modify(|r| r.set_CEN(false));
+    }
+
+    /// Reset counter and prescale counter to 0.
+    pub fn reset(&mut self) {
+        let regs = self.regs();
+        let enabled = regs.TCR().read().CEN();
+        regs.TCR().write(|r| r.set_CRST(true));
+        regs.TCR().write(|r| r.set_CEN(enabled));
+    }
+
+    /// Return `true` if counter is running.
+    pub fn is_running(&self) -> bool {
+        self.regs().TCR().read().CEN()
+    }
+
+    /// Current counter value.
+    pub fn counter(&self) -> u32 {
+        self.regs().TC().read()
+    }
+
+    /// Run as a periodic timer, raising [`CTimerInterrupt::MATCH3`] every `ticks` counter ticks.
+    pub fn start_periodic(&mut self, ticks: u32) {
+        self.set_match(Channel::Ch3, ticks.saturating_sub(1));
+        self.configure_match(Channel::Ch3, true, true, false);
+        self.reset();
+        self.start();
+    }
+
+    /// Set match value of `channel`.
+    pub fn set_match(&mut self, channel: Channel, value: u32) {
+        self.regs().MR(channel as usize).write_value(value);
+    }
+
+    /// Get match value of `channel`.
+    pub fn get_match(&self, channel: Channel) -> u32 {
+        self.regs().MR(channel as usize).read()
+    }
+
+    /// Configure what happens when counter matches `channel`:
+    /// raise interrupt, reset counter and/or stop counter.
+    pub fn configure_match(&mut self, channel: Channel, interrupt: bool, reset: bool, stop: bool) {
+        let shift = channel as u32 * 3;
+        let bits = (interrupt as u32) | (reset as u32) << 1 | (stop as u32) << 2;
+        self.regs()
+            .MCR()
+            .modify(|r| r.0 = (r.0 & !(0b111 << shift)) | bits << shift);
+    }
+
+    /// Set match output action of `channel`, the output drives MATn pin.
+    pub fn set_match_action(&mut self, channel: Channel, action: MatchAction) {
+        let shift = 4 + channel as u32 * 2;
+        self.regs()
+            .EMR()
+            .modify(|r| r.0 = (r.0 & !(0b11 << shift)) | (action as u32) << shift);
+    }
+
+    /// Force match output level of `channel`.
+    pub fn set_match_output(&mut self, channel: Channel, high: bool) {
+        let mask = 1 << channel as u32;
+        self.regs()
+            .EMR()
+            .modify(|r| r.0 = if high { r.0 | mask } else { r.0 & !mask });
+    }
+
+    /// Current match output level of `channel`.
+    pub fn match_output(&self, channel: Channel) -> bool {
+        self.regs().EMR().read().0 & (1 << channel as u32) != 0
+    }
+
+    /// Route match output of `pin` to the pin.
+    pub fn match_pin<P>(&mut self, mut pin: P) -> P
+    where
+        P: Pin<Module = Const<N>>,
+    {
+        prepare(&mut pin);
+        pin
+    }
+
+    /// Select INPUTMUX `input` number as capture source of `channel`.
+    pub fn set_capture_input(&mut self, channel: Channel, input: u8) {
+        let inputmux = unsafe { INPUTMUX0::instance() }.address();
+        let addr = inputmux + CAPTURE_INPUT_OFFSET[N as usize] + channel as usize * 4;
+        unsafe { (addr as *mut u32).write_volatile(input as u32) };
+    }
+
+    /// Select capture input pin `pin` as capture source of `channel`.
+    pub fn capture_pin<P: CapturePin>(&mut self, channel: Channel, mut pin: P) -> P {
+        pin.analog(false);
+        pin.set_mux(P::MUX);
+        self.set_capture_input(channel, P::INPUT);
+        pin
+    }
+
+    /// Configure capture `edge` of `channel`, `None` disables capture.
+    pub fn configure_capture(
+        &mut self,
+        channel: Channel,
+        edge: Option<CaptureEdge>,
+        interrupt: bool,
+    ) {
+        let shift = channel as u32 * 3;
+        let edges = match edge {
+            None => 0,
+            Some(CaptureEdge::Rising) => 0b01,
+            Some(CaptureEdge::Falling) => 0b10,
+            Some(CaptureEdge::Both) => 0b11,
+        };
+        let bits = edges | (interrupt as u32) << 2;
+        self.regs()
+            .CCR()
+            .modify(|r| r.0 = (r.0 & !(0b111 << shift)) | bits << shift);
+    }
+
+    /// Counter value of last capture event on `channel`.
+    pub fn capture(&self, channel: Channel) -> u32 {
+        self.regs().CR(channel as usize).read()
+    }
+
+    /// Read interrupt flags.
+    pub fn interrupt_flags(&self) -> CTimerInterrupt {
+        CTimerInterrupt::from_bits_truncate(self.regs().IR().read().0)
+    }
+
+    /// Clear interrupt flags.
+    pub fn clear_interrupt_flags(&mut self, flags: CTimerInterrupt) {
+        self.regs().IR().write(|r| r.0 = flags.bits());
+    }
+
+    /// Set PWM period to `period` counter ticks and split match channels 0 to 2
+    /// into PWM channels, match channel 3 resets the counter every period.
+    ///
+    /// A channel switches to PWM mode with 0% duty cycle when it gets a pin or
+    /// its duty cycle is first set, unused channels are left alone. Channels of
+    /// an earlier split are turned off. The channels borrow the timer, to change
+    /// the period drop them and split again.
+    pub fn pwm_channels(&mut self, period: u32) -> PwmChannels<'_, N> {
+        let regs = self.regs();
+        regs.PWMC().modify(|r| r.0 &= !0b111);
+        regs.MCR().modify(|r| r.0 &= !(0b111 << 24));
+        self.set_match(Channel::Ch3, period.saturating_sub(1));
+        self.configure_match(Channel::Ch3, false, true, false);
+        PwmChannels {
+            ch0: PwmChannel::new(regs),
+            ch1: PwmChannel::new(regs),
+            ch2: PwmChannel::new(regs),
+        }
+    }
+
+    /// Use as blocking [`Delay`], match channel 0 stops the counter when the delay ends.
+    pub fn into_delay(mut self) -> Delay<N> {
+        self.stop();
+        self.clear_config();
+        self.configure_match(Channel::Ch0, false, true, true);
+        Delay { timer: self }
+    }
+
+    /// Use as [`CountDown`] timer, match channel 0 ends each period.
+    pub fn into_count_down(mut self) -> CountDown<N> {
+        self.stop();
+        self.clear_config();
+        CountDown { timer: self }
+    }
+
+    /// Clear match, capture and PWM configuration left over by earlier use,
+    /// e.g. a channel 3 reset from [`Self::pwm_channels`] would cut periods short.
+    fn clear_config(&mut self) {
+        let regs = self.regs();
+        regs.MCR().write(|r| r.0 = 0);
+        regs.CCR().write(|r| r.0 = 0);
+        regs.EMR().write(|r| r.0 = 0);
+        regs.PWMC().write(|r| r.0 = 0);
+        regs.IR().write(|r| r.0 = CTimerInterrupt::all().bits());
+    }
+
+    /// Convert `time` in `unit` fractions of a second to counter ticks, rounded up.
+    fn ticks(&self, time: u32, unit: u32) -> u64 {
+        (time as u64 * self.tick_freq() as u64).div_ceil(unit as u64)
+    }
+
+    fn regs(&self) -> crate::pac::ctimer::CTIMER {
+        self.ctimer.regs()
+    }
+}
+
+/// CTIMER errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CTimerError {
+    /// Timer is not running.
+    NotRunning,
+}
+
+/// Blocking delay on a CTIMER, independent of SysTick.
+///
+/// Each delay resets and starts the counter, match channel 0 stops it after
+/// the requested number of ticks. Resolution is one counter tick.
+pub struct Delay<const N: u8> {
+    timer: CTimer<N>,
+}
+
+impl<const N: u8> Delay<N> {
+    /// Wait for `ticks` counter ticks.
+    pub fn delay_ticks(&mut self, mut ticks: u64) {
+        while ticks > 0 {
+            let chunk = ticks.min(u32::MAX as u64) as u32;
+            self.timer.set_match(Channel::Ch0, chunk);
+            self.timer.regs().TCR().write(|r| r.set_CRST(true));
+            self.timer.start();
+            while self.timer.is_running() {}
+            ticks -= chunk as u64;
+        }
+    }
+
+    /// Release the CTIMER driver.
+    pub fn release(mut self) -> CTimer<N> {
+        self.timer.stop();
+        self.timer
+            .configure_match(Channel::Ch0, false, false, false);
+        self.timer
+    }
+}
+
+impl<const N: u8> eh1::delay::DelayNs for Delay<N> {
+    fn delay_ns(&mut self, ns: u32) {
+        let ticks = self.timer.ticks(ns, 1_000_000_000);
+        self.delay_ticks(ticks);
+    }
+
+    fn delay_us(&mut self, us: u32) {
+        let ticks = self.timer.ticks(us, 1_000_000);
+        self.delay_ticks(ticks);
+    }
+
+    fn delay_ms(&mut self, ms: u32) {
+        let ticks = self.timer.ticks(ms, 1_000);
+        self.delay_ticks(ticks);
+    }
+}
+
+/// Periodic count down timer on a CTIMER.
+///
+/// Match channel 0 resets the counter at the end of each period and latches
+/// [`CTimerInterrupt::MATCH0`], [`CountDown::wait`] consumes it. Periods
+/// elapsed since the last `wait` are reported once. For an interrupt every
+/// period unmask the CTIMER interrupt in NVIC and call `wait` from its handler.
+pub struct CountDown<const N: u8> {
+    timer: CTimer<N>,
+}
+
+impl<const N: u8> CountDown<N> {
+    /// Start counting down `ticks` counter ticks, restarts automatically.
+    pub fn start(&mut self, ticks: u32) {
+        let timer = &mut self.timer;
+        timer.stop();
+        timer.set_match(Channel::Ch0, ticks.saturating_sub(1));
+        timer.configure_match(Channel::Ch0, true, true, false);
+        timer.clear_interrupt_flags(CTimerInterrupt::MATCH0);
+        timer.regs().TCR().write(|r| r.set_CRST(true));
+        timer.start();
+    }
+
+    /// Start counting down `us` microseconds, saturates at `u32::MAX` ticks.
+    pub fn start_us(&mut self, us: u32) {
+        let ticks = self.timer.ticks(us, 1_000_000);
+        self.start(ticks.min(u32::MAX as u64) as u32);
+    }
+
+    /// Return `Ok` if a period elapsed since last call, clearing the period interrupt.
+    pub fn wait(&mut self) -> nb::Result<(), Infallible> {
+        if !self.is_pending() {
+            return Err(nb::Error::WouldBlock);
+        }
+        self.clear_interrupt();
+        Ok(())
+    }
+
+    /// Stop the timer, returns [`CTimerError::NotRunning`] if it was not started.
+    pub fn cancel(&mut self) -> Result<(), CTimerError> {
+        if !self.timer.is_running() {
+            return Err(CTimerError::NotRunning);
+        }
+        self.timer.stop();
+        Ok(())
+    }
+
+    /// Return `true` if the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_running()
+    }
+
+    /// Counter ticks left in current period.
+    pub fn remaining(&self) -> u32 {
+        let period = self.timer.get_match(Channel::Ch0) + 1;
+        period.saturating_sub(self.timer.counter())
+    }
+
+    /// Return `true` if the period interrupt is pending.
+    pub fn is_pending(&self) -> bool {
+        self.timer
+            .interrupt_flags()
+            .contains(CTimerInterrupt::MATCH0)
+    }
+
+    /// Clear the period interrupt.
+    pub fn clear_interrupt(&mut self) {
+        self.timer.clear_interrupt_flags(CTimerInterrupt::MATCH0);
+    }
+
+    /// Stop the timer and release the CTIMER driver.
+    pub fn release(mut self) -> CTimer<N> {
+        self.timer.stop();
+        self.timer
+            .configure_match(Channel::Ch0, false, false, false);
+        self.clear_interrupt();
+        self.timer
+    }
+}
+
+/// PWM match channels 0 to 2 of a CTIMER, see [`CTimer::pwm_channels`].
+pub struct PwmChannels<'a, const N: u8> {
+    pub ch0: PwmChannel<'a, N, MAT0, ()>,
+    pub ch1: PwmChannel<'a, N, MAT1, ()>,
+    pub ch2: PwmChannel<'a, N, MAT2, ()>,
+}
+
+/// CTIMER PWM channel on match output `S`.
+///
+/// Output is low from counter reset until the channel match, then high.
+pub struct PwmChannel<'a, const N: u8, S, PIN> {
+    pin: PIN,
+    enabled: bool,
+    regs: crate::pac::ctimer::CTIMER,
+    _timer: PhantomData<(&'a mut CTimer<N>, S)>,
+}
+
+impl<'a, const N: u8, S: Signal> PwmChannel<'a, N, S, ()> {
+    fn new(regs: crate::pac::ctimer::CTIMER) -> Self {
+        Self {
+            pin: (),
+            enabled: false,
+            regs,
+            _timer: PhantomData,
+        }
+    }
+
+    /// Drive match output pin `pin` with this channel.
+    pub fn with_pin<P>(mut self, mut pin: P) -> PwmChannel<'a, N, S, P>
+    where
+        P: Pin<Module = Const<N>, Signal = S>,
+    {
+        // output is low before the pin is switched over
+        self.enable();
+        prepare(&mut pin);
+        PwmChannel {
+            pin,
+            enabled: true,
+            regs: self.regs,
+            _timer: PhantomData,
+        }
+    }
+}
+
+impl<const N: u8, S: Signal, PIN> PwmChannel<'_, N, S, PIN> {
+    /// Switch to PWM mode with 0% duty cycle, if not done yet.
+    fn enable(&mut self) {
+        if self.enabled {
+            return;
+        }
+        self.enabled = true;
+        let period = self.period();
+        let ch = S::CHANNEL as usize;
+        // match after period is never reached, 0% duty cycle
+        self.regs.MR(ch).write_value(period);
+        self.regs.MSR(ch).write_value(period);
+        // update match from shadow register on counter reset
+        self.regs.MCR().modify(|r| r.0 |= 1 << (24 + ch));
+        self.regs.PWMC().modify(|r| r.0 |= 1 << ch);
+    }
+
+    /// PWM period in counter ticks.
+    pub fn period(&self) -> u32 {
+        self.regs.MR(3).read() + 1
+    }
+
+    /// Set high time in counter ticks, applied at next period.
+    pub fn set_high_ticks(&mut self, ticks: u32) {
+        self.enable();
+        let value = self.period().saturating_sub(ticks);
+        self.regs.MSR(S::CHANNEL as usize).write_value(value);
+    }
+
+    /// Disable PWM and release the pin.
+    pub fn release(self) -> PIN {
+        let ch = S::CHANNEL;
+        self.regs.PWMC().modify(|r| r.0 &= !(1 << ch));
+        self.regs.MCR().modify(|r| r.0 &= !(1 << (24 + ch)));
+        self.pin
+    }
+}
+
+impl<const N: u8, S, PIN> eh1::pwm::ErrorType for PwmChannel<'_, N, S, PIN> {
+    type Error = Infallible;
+}
+impl<const N: u8, S: Signal, PIN> eh1::pwm::SetDutyCycle for PwmChannel<'_, N, S, PIN> {
+    fn max_duty_cycle(&self) -> u16 {
+        self.period().min(u16::MAX as u32) as u16
+    }
+
+    fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Self::Error> {
+        let period = self.period() as u64;
+        let max = self.max_duty_cycle().max(1) as u64;
+        let ticks = (period * duty as u64 / max) as u32;
+        self.set_high_ticks(ticks);
+        Ok(())
+    }
+}
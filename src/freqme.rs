@@ -0,0 +1,182 @@
+//! Frequency Measurement (FREQME)
+//!
+//! FREQME counts target clock cycles during `2^scale - 1` reference clock cycles,
+//! so the target frequency is `(result - 2) * reference_hz / (2^scale - 1)`.
+//!
+//! # Example
+//!
+//! ```rust
+//! let mut freqme = FreqMe::new(unsafe { pac::freqme::FREQME0::instance() });
+//! // measure FIRC against the 12 MHz SIRC
+//! let firc_hz = freqme
+//!     .measure(FreqMeClock::FroHf, FreqMeClock::FroLF, 12_000_000, 16)
+//!     .unwrap();
+//! ```
+
+use crate::{
+    pac::{freqme::Instance, inputmux::INPUTMUX0},
+    syscon::PeripheralGate,
+};
+
+/// FREQME Errors
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FreqMeError {
+    /// Reference scale must be between 1 and 31.
+    InvalidScale,
+    /// Target clock is not running, or too slow for given reference window.
+    NoClock,
+    /// Measurement did not finish, reference clock is not running.
+    Timeout,
+}
+
+/// Upper bound of core clock, used to derive the measurement timeout.
+const MAX_CORE_CLOCK: u64 = 250_000_000;
+
+/// FREQME reference and target clock.
+///
+/// The value is the input number of INPUTMUX FREQMEAS_REF/FREQMEAS_TAR.
+#[cfg(feature = "mcxa")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FreqMeClock {
+    /// SOSC or external reference clock.
+    ClkIn = 0,
+    /// SIRC 12M.
+    FroLF = 1,
+    /// FIRC.
+    FroHf = 2,
+    Clk16K = 4,
+    SlowClk = 5,
+    /// FREQME_CLK_IN0 pin.
+    ClkIn0 = 6,
+    /// FREQME_CLK_IN1 pin.
+    ClkIn1 = 7,
+    ClkOut = 12,
+    #[cfg(feature = "mcxa2")]
+    SPll = 13,
+}
+
+/// FREQME reference and target clock.
+///
+/// MCX N numbers FREQMEAS inputs differently from MCX A, the value is the raw
+/// INPUTMUX FREQMEAS_REF/FREQMEAS_TAR input number from the reference manual.
+#[cfg(feature = "mcxn")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FreqMeClock(pub u8);
+
+impl FreqMeClock {
+    const fn input(self) -> u8 {
+        #[cfg(feature = "mcxa")]
+        return self as u8;
+        #[cfg(feature = "mcxn")]
+        return self.0;
+    }
+}
+
+/// Frequency Measurement driver.
+pub struct FreqMe<const N: u8> {
+    freqme: Instance<N>,
+}
+
+impl<const N: u8> FreqMe<N> {
+    /// Create a new FREQME driver.
+    pub fn new(mut freqme: Instance<N>) -> Self
+    where
+        Instance<N>: PeripheralGate,
+    {
+        freqme.acquire();
+
+        // FREQME clock selection lives in INPUTMUX
+        unsafe { INPUTMUX0::instance() }.acquire();
+
+        Self { freqme }
+    }
+
+    /// Release FREQME instance.
+    pub fn release(mut self) -> Instance<N>
+    where
+        Instance<N>: PeripheralGate,
+    {
+        unsafe { INPUTMUX0::instance() }.release();
+        self.freqme.release();
+        self.freqme
+    }
+
+    /// Start a measurement of `target` clock, counting `2^scale - 1` cycles of `reference` clock.
+    pub fn start(
+        &mut self,
+        target: FreqMeClock,
+        reference: FreqMeClock,
+        scale: u8,
+    ) -> Result<(), FreqMeError> {
+        if scale == 0 || scale > 31 {
+            return Err(FreqMeError::InvalidScale);
+        }
+
+        let inputmux = unsafe { INPUTMUX0::instance() }.regs();
+        inputmux
+            .FREQMEAS_REF()
+            .write(|r| r.set_INP(reference.input()));
+        inputmux.FREQMEAS_TAR().write(|r| r.set_INP(target.input()));
+
+        self.freqme.regs().CTRL_W().write(|r| {
+            r.set_REF_SCALE(scale);
+            r.set_PULSE_MODE(false);
+            r.set_MEASURE_IN_PROGRESS(true);
+        });
+
+        Ok(())
+    }
+
+    /// Return `true` if a measurement is in progress.
+    pub fn busy(&self) -> bool {
+        self.freqme.regs().CTRL_R().read().MEASURE_IN_PROGRESS()
+    }
+
+    /// Abort the measurement in progress.
+    pub fn stop(&mut self) {
+        self.freqme
+            .regs()
+            .CTRL_W()
+            .write(|r| r.set_MEASURE_IN_PROGRESS(false));
+    }
+
+    /// Return raw result of last measurement, the count of target clock cycles.
+    pub fn result(&self) -> u32 {
+        self.freqme.regs().CTRL_R().read().RESULT()
+    }
+
+    /// Measure `target` clock frequency in Hz, blocking.
+    ///
+    /// `reference_hz` is the frequency of `reference` clock, the larger `scale` is,
+    /// the more accurate and slower the measurement is.
+    ///
+    /// Returns [`FreqMeError::Timeout`] if the measurement takes much longer than
+    /// the reference window, assuming a core clock of at most 250 MHz.
+    pub fn measure(
+        &mut self,
+        target: FreqMeClock,
+        reference: FreqMeClock,
+        reference_hz: u32,
+        scale: u8,
+    ) -> Result<u32, FreqMeError> {
+        self.start(target, reference, scale)?;
+
+        let mut timeout =
+            ((1u64 << scale) * MAX_CORE_CLOCK * 2).div_ceil(reference_hz.max(1) as u64);
+        while self.busy() {
+            if timeout == 0 {
+                self.stop();
+                return Err(FreqMeError::Timeout);
+            }
+            timeout -= 1;
+        }
+
+        let result = self.result();
+        if result < 2 {
+            return Err(FreqMeError::NoClock);
+        }
+
+        Ok(((result as u64 - 2) * reference_hz as u64 / ((1u64 << scale) - 1)) as u32)
+    }
+}
@@ -23,12 +23,26 @@
 //! // clear interrupt flag
 //! btn.clear_interrupt_flag();
 //! ```
+//!
+//! # Async
+//!
+//! With `async` feature, [`Input`] implements `embedded_hal_async::digital::Wait`.
+//! Call `on_interrupt` from the GPIO port's interrupt handler and unmask it in NVIC.
+//!
+//! ```rust
+//! #[interrupt]
+//! fn GPIO0() {
+//!     gpio::on_interrupt::<0>();
+//! }
+//!
+//! btn.wait_for_falling_edge().await.unwrap();
+//! ```
 
 use crate::{
     consts::{Const, Unsigned},
     pac::gpio::Instance,
-    port::Port,
-    syscon::{PeripheralCC, PeripheralRST},
+    port::{Port, PortPin},
+    syscon::PeripheralGate,
 };
 use core::convert::Infallible;
 use eh1;
@@ -87,17 +101,48 @@ where
     gpio: crate::pac::gpio::GPIO,
 }
 
+/// Type erased GPIO digital output pin, see [`Output::degrade`].
+pub struct AnyOutput {
+    port: u8,
+    pin: u8,
+    gpio: crate::pac::gpio::GPIO,
+}
+
+/// Type erased GPIO digital input pin, see [`Input::degrade`].
+pub struct AnyInput {
+    port: u8,
+    pin: u8,
+    gpio: crate::pac::gpio::GPIO,
+}
+
+/// GPIO pin whose direction can be changed at runtime.
+pub struct Flex<P>
+where
+    P: Port,
+{
+    pin: P,
+    gpio: crate::pac::gpio::GPIO,
+}
+
 impl<const N: u8> GPIO<N> {
     /// Create a new GPIO driver.
     pub fn new(mut gpio: Instance<N>) -> Self
     where
-        Instance<N>: PeripheralRST + PeripheralCC,
+        Instance<N>: PeripheralGate,
     {
-        gpio.reset();
-        gpio.enable_clock(true);
+        gpio.acquire();
         Self { gpio }
     }
 
+    /// Release GPIO instance, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> Instance<N>
+    where
+        Instance<N>: PeripheralGate,
+    {
+        self.gpio.release();
+        self.gpio
+    }
+
     /// Set a pin into GPIO digital input pin.
     /// See also [`Input`].
     pub fn input<P>(&mut self, mut pin: P) -> Input<P>
@@ -132,7 +177,59 @@ impl<const N: u8> GPIO<N> {
         }
     }
 
-    fn regs(&self) -> crate::pac::gpio::GPIO {
+    /// Set a pin into GPIO flexible pin, starts as input.
+    /// See also [`Flex`].
+    pub fn flex<P>(&mut self, mut pin: P) -> Flex<P>
+    where
+        P: Port<PORT = Const<N>>,
+    {
+        pin.set_mux(0);
+        pin.open_drain(false);
+        self.regs()
+            .PDDR()
+            .modify(|r| r.set_PDD(P::PIN::USIZE, false));
+
+        Flex {
+            pin,
+            gpio: self.regs(),
+        }
+    }
+
+    /// Group pins of this GPIO into a [`GpioBus`].
+    /// `pins` is a tuple of [`Output`], [`Input`] or [`Flex`] pins, or an array of [`AnyOutput`] or
+    /// [`AnyInput`] pins, the first pin is bit 0 of the bus value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a type erased pin is not on port `N` or the bus is wider than 32 pins.
+    pub fn bus<PINS>(&mut self, pins: PINS) -> GpioBus<N, PINS>
+    where
+        PINS: BusPins<N>,
+    {
+        assert!(PINS::WIDTH <= 32, "GPIO bus wider than 32 pins");
+        let mut mask = 0;
+        for i in 0..PINS::WIDTH {
+            assert_eq!(pins.port_number(i), N, "GPIO bus pin on another port");
+            mask |= 1 << pins.pin_number(i);
+        }
+
+        GpioBus {
+            pins,
+            mask,
+            gpio: self.regs(),
+        }
+    }
+
+    /// Create a port level interrupt dispatcher of this GPIO.
+    /// See also [`GpioInterrupts`].
+    pub fn interrupts(&self) -> GpioInterrupts<N> {
+        GpioInterrupts {
+            gpio: self.regs(),
+            handlers: [None; 32],
+        }
+    }
+
+    pub(crate) fn regs(&self) -> crate::pac::gpio::GPIO {
         self.gpio.regs()
     }
 }
@@ -172,6 +269,16 @@ impl<P: Port> Output<P> {
         self.pin
     }
 
+    /// Erase pin type, so pins of different ports can be stored together.
+    /// Convert back with [`TryFrom`].
+    pub fn degrade(self) -> AnyOutput {
+        AnyOutput {
+            port: P::PORT::USIZE as u8,
+            pin: P::PIN::USIZE as u8,
+            gpio: self.gpio,
+        }
+    }
+
     /// Access the raw pin.
     pub fn pin(&self) -> &P {
         &self.pin
@@ -223,6 +330,16 @@ impl<P: Port> Input<P> {
         self.pin
     }
 
+    /// Erase pin type, so pins of different ports can be stored together.
+    /// Convert back with [`TryFrom`].
+    pub fn degrade(self) -> AnyInput {
+        AnyInput {
+            port: P::PORT::USIZE as u8,
+            pin: P::PIN::USIZE as u8,
+            gpio: self.gpio,
+        }
+    }
+
     /// Access the raw pin.
     pub fn pin(&self) -> &P {
         &self.pin
@@ -234,12 +351,528 @@ impl<P: Port> Input<P> {
     }
 }
 
+macro_rules! any_pin_common {
+    ($name:ident, $typed:ident) => {
+        impl $name {
+            /// Get current GPIO pin mask.
+            pub const fn mask(&self) -> u32 {
+                1 << self.pin as u32
+            }
+
+            /// Port number of the pin.
+            pub const fn port_number(&self) -> u8 {
+                self.port
+            }
+
+            /// Pin number in port.
+            pub const fn pin_number(&self) -> u8 {
+                self.pin
+            }
+
+            /// Return `true` if GPIO pin is set.
+            pub fn is_set(&self) -> bool {
+                self.gpio.PDR(self.pin as usize).read().0 != 0
+            }
+
+            /// Return `true` if GPIO pin is cleared.
+            pub fn is_clear(&self) -> bool {
+                self.gpio.PDR(self.pin as usize).read().0 == 0
+            }
+        }
+
+        impl<const PORT: u8, const PIN: u8> TryFrom<$name> for $typed<PortPin<PORT, PIN>> {
+            type Error = $name;
+
+            /// Convert back into typed pin, returns the pin unchanged if port or pin doesn't match.
+            fn try_from(value: $name) -> Result<Self, Self::Error> {
+                if value.port == PORT && value.pin == PIN {
+                    Ok($typed {
+                        pin: unsafe { PortPin::new() },
+                        gpio: value.gpio,
+                    })
+                } else {
+                    Err(value)
+                }
+            }
+        }
+
+        impl eh1::digital::ErrorType for $name {
+            type Error = Infallible;
+        }
+    };
+}
+any_pin_common!(AnyOutput, Output);
+any_pin_common!(AnyInput, Input);
+
+impl AnyOutput {
+    /// Set GPIO pin output.
+    pub fn set(&self) {
+        self.gpio.PSOR().write(|r| r.0 = self.mask());
+    }
+
+    /// Clear GPIO pin output.
+    pub fn clear(&self) {
+        self.gpio.PCOR().write(|r| r.0 = self.mask());
+    }
+
+    /// Toggle GPIO pin output.
+    pub fn toggle(&self) {
+        self.gpio.PTOR().write(|r| r.0 = self.mask());
+    }
+}
+impl AnyInput {
+    /// Set GPIO pin's interrupt configuration.
+    /// Use [`GPIOIRQConfig::Disabled`] to disable GPIO pin's interrupt.
+    pub fn set_interrupt_config(&mut self, irq: GPIOIRQConfig) {
+        self.gpio.ICR(self.pin as usize).write(|r| {
+            r.set_ISF(true);
+            r.set_IRQC(irq as u8);
+        });
+    }
+
+    /// Read GPIO pin's interrupt flag.
+    #[inline]
+    pub fn get_interrupt_flag(&self) -> bool {
+        self.gpio.ICR(self.pin as usize).read().ISF()
+    }
+
+    /// Clear GPIO pin's interrupt flag by setting bit.
+    pub fn clear_interrupt_flag(&mut self) {
+        self.gpio.ICR(self.pin as usize).modify(|r| r.set_ISF(true));
+    }
+}
+
+impl<P: Port> Flex<P> {
+    /// Get current GPIO pin mask.
+    pub const fn mask(&self) -> u32 {
+        1 << P::PIN::USIZE as u32
+    }
+
+    /// Get current pin direction.
+    pub fn direction(&self) -> Direction {
+        if self.gpio.PDDR().read().PDD(P::PIN::USIZE) {
+            Direction::Output
+        } else {
+            Direction::Input
+        }
+    }
+
+    /// Set pin as input.
+    pub fn set_as_input(&mut self) {
+        self.pin.open_drain(false);
+        self.set_direction(Direction::Input);
+    }
+
+    /// Set pin as push-pull output.
+    pub fn set_as_output(&mut self) {
+        self.pin.open_drain(false);
+        self.set_direction(Direction::Output);
+    }
+
+    /// Set pin as open-drain output.
+    /// The pin level can still be read by [`Flex::is_set`].
+    pub fn set_as_open_drain(&mut self) {
+        self.pin.open_drain(true);
+        self.set_direction(Direction::Output);
+    }
+
+    fn set_direction(&mut self, dir: Direction) {
+        let output = matches!(dir, Direction::Output);
+        cortex_m::interrupt::free(|_| {
+            self.gpio
+                .PDDR()
+                .modify(|r| r.set_PDD(P::PIN::USIZE, output));
+        });
+    }
+
+    /// Set GPIO pin output.
+    pub fn set(&mut self) {
+        self.gpio.PSOR().write(|r| r.0 = self.mask());
+    }
+
+    /// Clear GPIO pin output.
+    pub fn clear(&mut self) {
+        self.gpio.PCOR().write(|r| r.0 = self.mask());
+    }
+
+    /// Toggle GPIO pin output.
+    pub fn toggle(&mut self) {
+        self.gpio.PTOR().write(|r| r.0 = self.mask());
+    }
+
+    /// Return `true` if GPIO pin input level is high.
+    pub fn is_set(&self) -> bool {
+        self.gpio.PDIR().read().PDI(P::PIN::USIZE)
+    }
+
+    /// Return `true` if GPIO pin input level is low.
+    pub fn is_clear(&self) -> bool {
+        !self.is_set()
+    }
+
+    /// Return `true` if GPIO pin output is set.
+    pub fn is_output_set(&self) -> bool {
+        self.gpio.PDOR().read().PDO(P::PIN::USIZE)
+    }
+
+    /// Release a GPIO pin.
+    pub fn release(self) -> P {
+        self.pin
+    }
+
+    /// Access the raw pin.
+    pub fn pin(&self) -> &P {
+        &self.pin
+    }
+
+    /// Access the mutable raw pin.
+    pub fn mut_pin(&mut self) -> &mut P {
+        &mut self.pin
+    }
+}
+
+#[cfg(feature = "async")]
+fn disable_irqc(gpio: crate::pac::gpio::GPIO, pin: u8) {
+    gpio.ICR(pin as usize).write(|r| {
+        r.set_ISF(true);
+        r.set_IRQC(GPIOIRQConfig::Disabled as u8);
+    });
+}
+
+/// A GPIO pin which can be grouped into a [`GpioBus`] of GPIO `N`.
+pub trait BusPin<const N: u8> {
+    /// Pin number in GPIO port.
+    fn pin_number(&self) -> u8;
+
+    /// GPIO port of the pin, only type erased pins can differ from `N`.
+    fn port_number(&self) -> u8 {
+        N
+    }
+}
+impl<const N: u8, P: Port<PORT = Const<N>>> BusPin<N> for Output<P> {
+    fn pin_number(&self) -> u8 {
+        P::PIN::USIZE as u8
+    }
+}
+impl<const N: u8, P: Port<PORT = Const<N>>> BusPin<N> for Input<P> {
+    fn pin_number(&self) -> u8 {
+        P::PIN::USIZE as u8
+    }
+}
+impl<const N: u8, P: Port<PORT = Const<N>>> BusPin<N> for Flex<P> {
+    fn pin_number(&self) -> u8 {
+        P::PIN::USIZE as u8
+    }
+}
+impl<const N: u8> BusPin<N> for AnyOutput {
+    fn pin_number(&self) -> u8 {
+        self.pin
+    }
+
+    fn port_number(&self) -> u8 {
+        self.port
+    }
+}
+impl<const N: u8> BusPin<N> for AnyInput {
+    fn pin_number(&self) -> u8 {
+        self.pin
+    }
+
+    fn port_number(&self) -> u8 {
+        self.port
+    }
+}
+
+/// Pins of a [`GpioBus`], implemented for tuples of up to 16 [`BusPin`] and arrays of one [`BusPin`] type.
+pub trait BusPins<const N: u8> {
+    /// Number of pins on the bus.
+    const WIDTH: usize;
+
+    /// Pin number of bus bit `index`.
+    fn pin_number(&self, index: usize) -> u8;
+
+    /// GPIO port of bus bit `index`.
+    fn port_number(&self, index: usize) -> u8;
+}
+impl<const N: u8, T: BusPin<N>, const K: usize> BusPins<N> for [T; K] {
+    const WIDTH: usize = K;
+
+    fn pin_number(&self, index: usize) -> u8 {
+        self[index].pin_number()
+    }
+
+    fn port_number(&self, index: usize) -> u8 {
+        self[index].port_number()
+    }
+}
+macro_rules! bus_pins_tuple {
+    ($width:literal: $($T:ident $i:tt),+) => {
+        impl<const N: u8, $($T: BusPin<N>),+> BusPins<N> for ($($T,)+) {
+            const WIDTH: usize = $width;
+
+            fn pin_number(&self, index: usize) -> u8 {
+                match index {
+                    $($i => self.$i.pin_number(),)+
+                    _ => unreachable!(),
+                }
+            }
+
+            fn port_number(&self, index: usize) -> u8 {
+                match index {
+                    $($i => self.$i.port_number(),)+
+                    _ => unreachable!(),
+                }
+            }
+        }
+    };
+}
+bus_pins_tuple!(1: A 0);
+bus_pins_tuple!(2: A 0, B 1);
+bus_pins_tuple!(3: A 0, B 1, C 2);
+bus_pins_tuple!(4: A 0, B 1, C 2, D 3);
+bus_pins_tuple!(5: A 0, B 1, C 2, D 3, E 4);
+bus_pins_tuple!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+bus_pins_tuple!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+bus_pins_tuple!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+bus_pins_tuple!(9: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+bus_pins_tuple!(10: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+bus_pins_tuple!(11: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+bus_pins_tuple!(12: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+bus_pins_tuple!(13: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12);
+bus_pins_tuple!(14: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, O 13);
+bus_pins_tuple!(15: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, O 13, Q 14);
+bus_pins_tuple!(16: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11, M 12, O 13, Q 14, R 15);
+
+/// Several pins of one GPIO port accessed together.
+///
+/// Bit `i` of a bus value maps to the `i`-th pin given to [`GPIO::bus`].
+///
+/// ```rust
+/// let d0 = gpio0.output(port0.p0);
+/// let d1 = gpio0.output(port0.p1);
+/// let mut bus = gpio0.bus((d0, d1));
+/// bus.write(0b10);
+///
+/// // Type erased pins, checked to be on GPIO0 when the bus is created.
+/// let pins = [gpio0.output(port0.p2).degrade(), gpio0.output(port0.p3).degrade()];
+/// let mut bus = gpio0.bus(pins);
+/// ```
+pub struct GpioBus<const N: u8, PINS> {
+    pins: PINS,
+    mask: u32,
+    gpio: crate::pac::gpio::GPIO,
+}
+
+impl<const N: u8, PINS: BusPins<N>> GpioBus<N, PINS> {
+    /// Get GPIO port mask of all bus pins.
+    pub const fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    /// Write `value` to the bus, all bus pins change in the same PDOR write.
+    pub fn write(&mut self, value: u32) {
+        let bits = self.port_bits(value);
+        cortex_m::interrupt::free(|_| {
+            self.gpio.PDOR().modify(|r| r.0 = (r.0 & !self.mask) | bits);
+        });
+    }
+
+    /// Read the bus pins with one PDIR access.
+    pub fn read(&self) -> u32 {
+        self.bus_value(self.gpio.PDIR().read().0)
+    }
+
+    /// Read the bus output data.
+    pub fn read_output(&self) -> u32 {
+        self.bus_value(self.gpio.PDOR().read().0)
+    }
+
+    /// Set bus bits which are set in `value`, others are unchanged.
+    pub fn set_bits(&mut self, value: u32) {
+        let bits = self.port_bits(value);
+        self.gpio.PSOR().write(|r| r.0 = bits);
+    }
+
+    /// Clear bus bits which are set in `value`, others are unchanged.
+    pub fn clear_bits(&mut self, value: u32) {
+        let bits = self.port_bits(value);
+        self.gpio.PCOR().write(|r| r.0 = bits);
+    }
+
+    /// Toggle bus bits which are set in `value`, others are unchanged.
+    pub fn toggle_bits(&mut self, value: u32) {
+        let bits = self.port_bits(value);
+        self.gpio.PTOR().write(|r| r.0 = bits);
+    }
+
+    /// Release bus pins.
+    pub fn release(self) -> PINS {
+        self.pins
+    }
+
+    /// Access the bus pins.
+    pub fn pins(&self) -> &PINS {
+        &self.pins
+    }
+
+    fn port_bits(&self, value: u32) -> u32 {
+        let mut bits = 0;
+        for i in 0..PINS::WIDTH {
+            if value & (1 << i) != 0 {
+                bits |= 1 << self.pins.pin_number(i);
+            }
+        }
+        bits
+    }
+
+    fn bus_value(&self, bits: u32) -> u32 {
+        let mut value = 0;
+        for i in 0..PINS::WIDTH {
+            if bits & (1 << self.pins.pin_number(i)) != 0 {
+                value |= 1 << i;
+            }
+        }
+        value
+    }
+}
+
+/// Pin event edge reported by [`GpioInterrupts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    Rising,
+    Falling,
+    /// Logic zero level interrupt.
+    Low,
+    /// Logic one level interrupt.
+    High,
+}
+
+/// GPIO pin interrupt event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GpioEvent {
+    pub pin: u8,
+    pub edge: Edge,
+}
+
+/// GPIO port interrupt dispatcher.
+///
+/// Reads port interrupt status flags once and maps them to pin handlers or events.
+/// Don't use it together with async `on_interrupt` on the same port, both clear the flags.
+///
+/// ```rust
+/// let mut irqs = gpio0.interrupts();
+/// irqs.set_handler(&btn, |event| { /* ... */ });
+///
+/// // In GPIO0 interrupt handler
+/// irqs.dispatch();
+/// // or
+/// for event in irqs.events() { /* ... */ }
+/// ```
+pub struct GpioInterrupts<const N: u8> {
+    gpio: crate::pac::gpio::GPIO,
+    handlers: [Option<fn(GpioEvent)>; 32],
+}
+
+impl<const N: u8> GpioInterrupts<N> {
+    /// Register `handler` for `pin`, replacing previous one.
+    pub fn set_handler<P>(&mut self, _pin: &Input<P>, handler: fn(GpioEvent))
+    where
+        P: Port<PORT = Const<N>>,
+    {
+        self.handlers[P::PIN::USIZE] = Some(handler);
+    }
+
+    /// Remove handler of `pin`.
+    pub fn remove_handler<P>(&mut self, _pin: &Input<P>)
+    where
+        P: Port<PORT = Const<N>>,
+    {
+        self.handlers[P::PIN::USIZE] = None;
+    }
+
+    /// Read pending pin flags.
+    pub fn pending(&self) -> u32 {
+        self.gpio.ISFR(0).read().0
+    }
+
+    /// Call handlers of pending pins.
+    /// Flags of handled pins are cleared with one write, others are kept.
+    pub fn dispatch(&self) {
+        let mut handled = 0;
+        for event in self.decode(self.pending()) {
+            if let Some(handler) = self.handlers[event.pin as usize] {
+                handled |= 1 << event.pin;
+                handler(event);
+            }
+        }
+        self.clear(handled);
+    }
+
+    /// Take all pending pin events, their flags are cleared with one write.
+    pub fn events(&self) -> GpioEvents {
+        let flags = self.pending();
+        self.clear(flags);
+        self.decode(flags)
+    }
+
+    /// Clear pin flags set in `mask`.
+    pub fn clear(&self, mask: u32) {
+        if mask != 0 {
+            self.gpio.ISFR(0).write(|r| r.0 = mask);
+        }
+    }
+
+    fn decode(&self, flags: u32) -> GpioEvents {
+        GpioEvents {
+            gpio: self.gpio,
+            flags,
+            level: self.gpio.PDIR().read().0,
+        }
+    }
+}
+
+/// Iterator of [`GpioEvent`], see [`GpioInterrupts::events`].
+pub struct GpioEvents {
+    gpio: crate::pac::gpio::GPIO,
+    flags: u32,
+    level: u32,
+}
+
+impl Iterator for GpioEvents {
+    type Item = GpioEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.flags == 0 {
+            return None;
+        }
+        let pin = self.flags.trailing_zeros();
+        self.flags &= self.flags - 1;
+
+        let edge = match self.gpio.ICR(pin as usize).read().IRQC() {
+            1 | 5 | 9 => Edge::Rising,
+            2 | 6 | 10 => Edge::Falling,
+            8 => Edge::Low,
+            12 => Edge::High,
+            // Either edge, use current pin level.
+            _ if self.level & (1 << pin) != 0 => Edge::Rising,
+            _ => Edge::Falling,
+        };
+        Some(GpioEvent {
+            pin: pin as u8,
+            edge,
+        })
+    }
+}
+
 impl<P: Port> eh1::digital::ErrorType for Input<P> {
     type Error = Infallible;
 }
 impl<P: Port> eh1::digital::ErrorType for Output<P> {
     type Error = Infallible;
 }
+impl<P: Port> eh1::digital::ErrorType for Flex<P> {
+    type Error = Infallible;
+}
 impl<P: Port> eh1::digital::InputPin for Input<P> {
     fn is_high(&mut self) -> Result<bool, Self::Error> {
         Ok(self.is_set())
@@ -277,3 +910,170 @@ impl<P: Port> eh1::digital::StatefulOutputPin for Output<P> {
         Ok(())
     }
 }
+impl<P: Port> eh1::digital::InputPin for Flex<P> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_set())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_clear())
+    }
+}
+impl<P: Port> eh1::digital::OutputPin for Flex<P> {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.set();
+        Ok(())
+    }
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.clear();
+        Ok(())
+    }
+}
+impl<P: Port> eh1::digital::StatefulOutputPin for Flex<P> {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_output_set())
+    }
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.is_output_set())
+    }
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        Flex::<P>::toggle(self);
+        Ok(())
+    }
+}
+impl eh1::digital::InputPin for AnyInput {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_set())
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_clear())
+    }
+}
+impl eh1::digital::OutputPin for AnyOutput {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.set();
+        Ok(())
+    }
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.clear();
+        Ok(())
+    }
+}
+impl eh1::digital::StatefulOutputPin for AnyOutput {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_set())
+    }
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.is_clear())
+    }
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        AnyOutput::toggle(self);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+static WAKERS: [[atomic_waker::AtomicWaker; 32]; crate::pac::gpio::LEN] =
+    [const { [const { atomic_waker::AtomicWaker::new() }; 32] }; crate::pac::gpio::LEN];
+
+/// GPIO port `N` interrupt handler for async waits.
+///
+/// Disables interrupt of every pin whose flag is set, clears the flag and wakes its waiting task.
+#[cfg(feature = "async")]
+pub fn on_interrupt<const N: u8>() {
+    let gpio = unsafe { Instance::<N>::instance() }.regs();
+    let mut flags = gpio.ISFR(0).read().0;
+    while flags != 0 {
+        let pin = flags.trailing_zeros() as usize;
+        flags &= flags - 1;
+
+        gpio.ICR(pin).modify(|r| {
+            r.set_IRQC(GPIOIRQConfig::Disabled as u8);
+            r.set_ISF(true);
+        });
+        WAKERS[N as usize][pin].wake();
+    }
+}
+
+/// Run a closure on drop, unless defused.
+#[cfg(feature = "async")]
+struct OnDrop<F: FnOnce()> {
+    f: core::mem::ManuallyDrop<F>,
+}
+
+#[cfg(feature = "async")]
+impl<F: FnOnce()> OnDrop<F> {
+    fn new(f: F) -> Self {
+        Self {
+            f: core::mem::ManuallyDrop::new(f),
+        }
+    }
+
+    fn defuse(self) {
+        core::mem::forget(self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<F: FnOnce()> Drop for OnDrop<F> {
+    fn drop(&mut self) {
+        unsafe { core::mem::ManuallyDrop::take(&mut self.f)() }
+    }
+}
+
+#[cfg(feature = "async")]
+async fn wait_for_irq(gpio: crate::pac::gpio::GPIO, port: usize, pin: usize, irq: GPIOIRQConfig) {
+    gpio.ICR(pin).write(|r| {
+        r.set_ISF(true);
+        r.set_IRQC(irq as u8);
+    });
+    // a cancelled wait must not leave the interrupt armed
+    let guard = OnDrop::new(|| {
+        disable_irqc(gpio, pin as u8);
+        WAKERS[port][pin].take();
+    });
+    core::future::poll_fn(|cx| {
+        WAKERS[port][pin].register(cx.waker());
+        if gpio.ICR(pin).read().IRQC() == GPIOIRQConfig::Disabled as u8 {
+            core::task::Poll::Ready(())
+        } else {
+            core::task::Poll::Pending
+        }
+    })
+    .await;
+    guard.defuse();
+}
+
+#[cfg(feature = "async")]
+impl<P: Port> eh1_async::digital::Wait for Input<P> {
+    async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+        if self.is_clear() {
+            let irq = GPIOIRQConfig::InterruptLogicOne;
+            wait_for_irq(self.gpio, P::PORT::USIZE, P::PIN::USIZE, irq).await;
+        }
+        Ok(())
+    }
+    async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+        if self.is_set() {
+            let irq = GPIOIRQConfig::InterruptLogicZero;
+            wait_for_irq(self.gpio, P::PORT::USIZE, P::PIN::USIZE, irq).await;
+        }
+        Ok(())
+    }
+    async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+        let irq = GPIOIRQConfig::InterruptRisingEdge;
+        wait_for_irq(self.gpio, P::PORT::USIZE, P::PIN::USIZE, irq).await;
+        Ok(())
+    }
+    async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+        let irq = GPIOIRQConfig::InterruptFallingEdge;
+        wait_for_irq(self.gpio, P::PORT::USIZE, P::PIN::USIZE, irq).await;
+        Ok(())
+    }
+    async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+        let irq = GPIOIRQConfig::InterruptEitherEdge;
+        wait_for_irq(self.gpio, P::PORT::USIZE, P::PIN::USIZE, irq).await;
+        Ok(())
+    }
+}
@@ -4,10 +4,16 @@
 
 pub use mcx_pac as pac;
 
+#[cfg(feature = "bsp")]
+pub mod bsp;
+pub mod clkout;
+pub mod ctimer;
 pub mod device;
+pub mod freqme;
 pub mod gpio;
 pub mod lpspi;
 pub mod lpuart;
+pub mod ostimer;
 pub mod port;
 pub mod scg;
 pub mod spc;
@@ -25,6 +31,14 @@ pub mod consts {
     impl<const N: u8> Unsigned for Const<N> {
         const USIZE: usize = N as usize;
     }
+    /// Number only known at runtime, port and pin of [`crate::port::AnyPin`].
+    ///
+    /// Drivers bound on `Const<N>` don't accept it.
+    #[derive(Debug)]
+    pub enum Dynamic {}
+    impl Unsigned for Dynamic {
+        const USIZE: usize = usize::MAX;
+    }
     macro_rules! ux {
         ($($Ux:ident => $N:literal,)+) => {
             $(pub type $Ux = Const<$N>;)+
@@ -52,15 +66,27 @@ pub mod prelude {
     #[cfg(feature = "rt")]
     pub use crate::pac::interrupt;
 
+    pub use crate::clkout::ClkOut;
+
+    pub use crate::ctimer::{CTimer, CTimerInterrupt};
+
+    pub use crate::freqme::{FreqMe, FreqMeClock};
+
     pub use crate::gpio::*;
 
     pub use crate::lpuart::{
         BaudRate, Direction as LpUartDirection, LpUart, LpUartInterrupt, Pins as LpUartPins,
     };
 
-    pub use crate::port::{device::*, Port, PortPin};
+    pub use crate::ostimer::OsTimer;
+
+    pub use crate::port::{
+        device::*, AnyPin, DriveStrength, PinConfig, Port, PortPin, Pull, PullValue, SlewRate,
+    };
 
-    pub use crate::scg::{Config as SCGConfig, PllConfig, PllSource, FIRC, SCG};
+    pub use crate::scg::{Config as SCGConfig, FIRC, SCG};
+    #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
+    pub use crate::scg::{PllConfig, PllSource};
 
     pub use crate::syscon::*;
 }
@@ -7,7 +7,7 @@ use crate::{
         lpuart::{regs::STAT, Instance},
     },
     port::lpuart::{prepare, Pin, RXD, TXD},
-    syscon::{PeripheralCC, PeripheralRST},
+    syscon::PeripheralGate,
 };
 
 /// LPUART Errors
@@ -119,6 +119,14 @@ where
     pub rx: RX,
 }
 
+/// Switch LP_FLEXCOMM `N` to its LPUART function, MCX N LPUARTs live in LP_FLEXCOMMs.
+#[cfg(feature = "mcxn")]
+fn select_flexcomm_uart<const N: u8>() {
+    let flexcomm = unsafe { crate::pac::lp_flexcomm::Instance::<N>::instance() }.regs();
+    // PERSEL 1: LPUART only
+    flexcomm.PSELID().modify(|r| r.set_PERSEL(1));
+}
+
 /// LPUART instance
 pub struct LpUart<const N: u8, PINS> {
     lpuart: Instance<N>,
@@ -133,10 +141,11 @@ where
     /// Create a new LPUART instance with given TX and RX pins
     pub fn new(mut lpuart: Instance<N>, mut pins: Pins<TX, RX>) -> Self
     where
-        Instance<N>: PeripheralCC + PeripheralRST,
+        Instance<N>: PeripheralGate,
     {
-        lpuart.reset();
-        lpuart.enable_clock(true);
+        lpuart.acquire();
+        #[cfg(feature = "mcxn")]
+        select_flexcomm_uart::<N>();
 
         prepare(&mut pins.tx);
         prepare(&mut pins.rx);
@@ -150,10 +159,11 @@ impl<const N: u8> LpUart<N, ()> {
     /// Create a new LPUART instance without pins
     pub fn without_pins(mut lpuart: Instance<N>) -> Self
     where
-        Instance<N>: crate::syscon::PeripheralCC + crate::syscon::PeripheralRST,
+        Instance<N>: PeripheralGate,
     {
-        lpuart.reset();
-        lpuart.enable_clock(true);
+        lpuart.acquire();
+        #[cfg(feature = "mcxn")]
+        select_flexcomm_uart::<N>();
 
         let mut ret = Self { lpuart, pins: () };
         ret.reset();
@@ -162,8 +172,14 @@ impl<const N: u8> LpUart<N, ()> {
 }
 impl<const N: u8, PINS> LpUart<N, PINS> {
     /// Reset LPUART to default and release instance and pins.
-    pub fn release(mut self) -> (Instance<N>, PINS) {
+    ///
+    /// LPUART clock is gated when the last user releases it.
+    pub fn release(mut self) -> (Instance<N>, PINS)
+    where
+        Instance<N>: PeripheralGate,
+    {
         self.reset();
+        self.lpuart.release();
         (self.lpuart, self.pins)
     }
 
@@ -0,0 +1,223 @@
+//! OS Event Timer (OSTIMER)
+//!
+//! OSTIMER is a free running 42-bit counter. Counter, capture and match
+//! registers are gray coded so they can be read safely across clock domains,
+//! the driver converts them to binary. The counter wraps after 2^42 ticks,
+//! about 50 days at 1 MHz.
+//!
+//! Clocked from `clk_16k` or `clk_1m` the timer keeps running in low power
+//! modes. The clock is selected in MRCC (MCX A) or SYSCON (MCX N), the driver
+//! only needs its frequency.
+//!
+//! The match register raises `OS_EVENT` interrupt when the counter reaches it.
+//!
+//! # Example
+//!
+//! ```rust
+//! let mut ostimer = OsTimer::new(unsafe { pac::ostimer::OSTIMER0::instance() }, 1_000_000);
+//!
+//! let now = ostimer.now();
+//! ostimer.set_match(now + 1_000);
+//! ostimer.listen();
+//! ```
+//!
+//! # Time keeping
+//!
+//! With `rtic-monotonic` feature, [`monotonic::OsTimerMonotonic`] implements
+//! `rtic_time::Monotonic`. With `embassy-time-driver` feature, [`time_driver`]
+//! implements `embassy_time_driver::Driver`. Both take over [`OsTimer`], call
+//! their `on_interrupt` from `OS_EVENT` interrupt handler and unmask it in NVIC.
+//!
+//! Their time is the counter extended to 64 bits in software, so it doesn't
+//! wrap. The match is never set more than half a counter period ahead, even
+//! with no timer pending, so `OS_EVENT` keeps the extension up to date. It
+//! must not be blocked for half a period.
+//!
+//! ```rust
+//! type Mono = OsTimerMonotonic<1_000_000>;
+//! Mono::start(ostimer);
+//!
+//! #[task(binds = OS_EVENT)]
+//! fn os_event(_: os_event::Context) {
+//!     unsafe { Mono::on_interrupt() };
+//! }
+//! ```
+
+#[cfg(feature = "rtic-monotonic")]
+pub mod monotonic;
+#[cfg(feature = "embassy-time-driver")]
+pub mod time_driver;
+
+use crate::{
+    pac::ostimer::{OSTIMER, OSTIMER0},
+    syscon::PeripheralGate,
+};
+
+/// Counter width in bits.
+pub const BITS: u32 = 42;
+/// Counter mask, the counter wraps to 0 after this value.
+pub const MASK: u64 = (1 << BITS) - 1;
+
+/// OSTIMER driver.
+pub struct OsTimer {
+    ostimer: OSTIMER0,
+    clock: u32,
+}
+
+impl OsTimer {
+    /// Create a new OSTIMER driver, `clock` is the OSTIMER clock frequency.
+    pub fn new(mut ostimer: OSTIMER0, clock: u32) -> Self {
+        ostimer.acquire();
+        // disable interrupt and clear flag
+        ostimer
+            .regs()
+            .OSEVENT_CTRL()
+            .write(|r| r.set_OSTIMER_INTRFLAG(true));
+        Self { ostimer, clock }
+    }
+
+    /// Disable interrupt and release instance, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> OSTIMER0 {
+        self.unlisten();
+        self.ostimer.release();
+        self.ostimer
+    }
+
+    /// OSTIMER clock frequency.
+    pub const fn clock(&self) -> u32 {
+        self.clock
+    }
+
+    /// Current counter value.
+    pub fn now(&self) -> u64 {
+        counter(self.regs())
+    }
+
+    /// Counter value latched by last capture event.
+    pub fn capture(&self) -> u64 {
+        let regs = self.regs();
+        let low = regs.CAPTURE_L().read() as u64;
+        let high = regs.CAPTURE_H().read().CAPTURE_VALUE() as u64;
+        gray_to_binary(high << 32 | low)
+    }
+
+    /// Set match value, interrupt is raised when the counter reaches it.
+    ///
+    /// Match compares for equality, a value already passed only matches after
+    /// the counter wraps.
+    pub fn set_match(&mut self, ticks: u64) {
+        write_match(self.regs(), ticks);
+    }
+
+    /// Get match value.
+    pub fn get_match(&self) -> u64 {
+        let regs = self.regs();
+        let low = regs.MATCH_L().read() as u64;
+        let high = regs.MATCH_H().read().MATCH_VALUE() as u64;
+        gray_to_binary(high << 32 | low)
+    }
+
+    /// Enable match interrupt.
+    pub fn listen(&mut self) {
+        set_interrupt(self.regs(), true);
+    }
+
+    /// Disable match interrupt.
+    pub fn unlisten(&mut self) {
+        set_interrupt(self.regs(), false);
+    }
+
+    /// Return `true` if match interrupt is pending.
+    pub fn is_pending(&self) -> bool {
+        self.regs().OSEVENT_CTRL().read().OSTIMER_INTRFLAG()
+    }
+
+    /// Clear match interrupt flag.
+    pub fn clear_interrupt(&mut self) {
+        clear_interrupt(self.regs());
+    }
+
+    fn regs(&self) -> OSTIMER {
+        self.ostimer.regs()
+    }
+}
+
+/// Register block of the single OSTIMER instance, for time keeping backends.
+#[cfg(any(feature = "rtic-monotonic", feature = "embassy-time-driver"))]
+fn regs() -> OSTIMER {
+    unsafe { OSTIMER0::instance() }.regs()
+}
+
+/// Last extended counter value returned by [`now`].
+#[cfg(any(feature = "rtic-monotonic", feature = "embassy-time-driver"))]
+static LAST: critical_section::Mutex<core::cell::Cell<u64>> =
+    critical_section::Mutex::new(core::cell::Cell::new(0));
+
+/// Counter extended to 64 bits, for time keeping backends.
+///
+/// Correct as long as it's called at least once per counter period, which
+/// [`write_alarm`] ensures.
+#[cfg(any(feature = "rtic-monotonic", feature = "embassy-time-driver"))]
+fn now() -> u64 {
+    critical_section::with(|cs| {
+        let last = LAST.borrow(cs);
+        let prev = last.get();
+        let mut now = (prev & !MASK) | counter(regs());
+        if now < prev {
+            // counter wrapped since last call
+            now += 1 << BITS;
+        }
+        last.set(now);
+        now
+    })
+}
+
+/// Set match to extended counter value `at`, at most half a counter period
+/// ahead so the interrupt keeps [`now`] up to date.
+#[cfg(any(feature = "rtic-monotonic", feature = "embassy-time-driver"))]
+fn write_alarm(at: u64) {
+    write_match(regs(), at.min(now() + (1 << (BITS - 1))));
+}
+
+/// Read counter, reading low word latches high word.
+fn counter(regs: OSTIMER) -> u64 {
+    let low = regs.EVTIMERL().read() as u64;
+    let high = regs.EVTIMERH().read().EVTIMER_COUNT_VALUE() as u64;
+    gray_to_binary(high << 32 | low)
+}
+
+fn write_match(regs: OSTIMER, ticks: u64) {
+    let gray = binary_to_gray(ticks & MASK);
+    // previous match value is still being synchronized
+    while regs.OSEVENT_CTRL().read().MATCH_WR_RDY() {}
+    regs.MATCH_L().write_value(gray as u32);
+    regs.MATCH_H()
+        .write(|r| r.set_MATCH_VALUE((gray >> 32) as u16));
+}
+
+fn set_interrupt(regs: OSTIMER, enable: bool) {
+    regs.OSEVENT_CTRL().modify(|r| {
+        // write 1 clears the flag
+        r.set_OSTIMER_INTRFLAG(false);
+        r.set_OSTIMER_INTENA(enable);
+    });
+}
+
+fn clear_interrupt(regs: OSTIMER) {
+    regs.OSEVENT_CTRL().modify(|r| r.set_OSTIMER_INTRFLAG(true));
+}
+
+const fn gray_to_binary(gray: u64) -> u64 {
+    let mut value = gray;
+    value ^= value >> 32;
+    value ^= value >> 16;
+    value ^= value >> 8;
+    value ^= value >> 4;
+    value ^= value >> 2;
+    value ^= value >> 1;
+    value
+}
+
+const fn binary_to_gray(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
@@ -0,0 +1,86 @@
+//! RTIC monotonic on OSTIMER
+
+use super::{clear_interrupt, now, regs, set_interrupt, write_alarm, OsTimer};
+use crate::pac::Interrupt;
+use cortex_m::peripheral::NVIC;
+use rtic_time::{
+    monotonic::TimerQueueBasedMonotonic,
+    timer_queue::{TimerQueue, TimerQueueBackend},
+};
+
+pub use fugit;
+pub use rtic_time::Monotonic;
+
+/// Match values closer than this to the counter may be passed before the
+/// match register is synchronized.
+const MIN_DELTA: u64 = 4;
+
+static TIMER_QUEUE: TimerQueue<OsTimerBackend> = TimerQueue::new();
+
+/// Timer queue backend of [`OsTimerMonotonic`].
+pub struct OsTimerBackend;
+
+impl TimerQueueBackend for OsTimerBackend {
+    type Ticks = u64;
+
+    fn now() -> Self::Ticks {
+        now()
+    }
+
+    fn set_compare(instant: Self::Ticks) {
+        write_alarm(instant);
+        if instant <= now() + MIN_DELTA {
+            Self::pend_interrupt();
+        }
+    }
+
+    fn clear_compare_flag() {
+        clear_interrupt(regs());
+    }
+
+    fn pend_interrupt() {
+        NVIC::pend(Interrupt::OS_EVENT);
+    }
+
+    fn enable_timer() {
+        set_interrupt(regs(), true);
+    }
+
+    /// Keep the interrupt firing every half counter period to extend the counter.
+    fn disable_timer() {
+        write_alarm(u64::MAX);
+    }
+
+    fn timer_queue() -> &'static TimerQueue<Self> {
+        &TIMER_QUEUE
+    }
+}
+
+/// RTIC monotonic ticking at `HZ`, the OSTIMER clock frequency.
+pub struct OsTimerMonotonic<const HZ: u32>;
+
+impl<const HZ: u32> OsTimerMonotonic<HZ> {
+    /// Start the monotonic, `timer` is kept running until reset.
+    pub fn start(timer: OsTimer) {
+        assert_eq!(timer.clock(), HZ);
+        TIMER_QUEUE.initialize(OsTimerBackend);
+        // fire every half counter period until the first timer is set
+        write_alarm(u64::MAX);
+        set_interrupt(regs(), true);
+    }
+
+    /// Call from `OS_EVENT` interrupt handler.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called from `OS_EVENT` interrupt handler.
+    pub unsafe fn on_interrupt() {
+        TIMER_QUEUE.on_monotonic_interrupt();
+    }
+}
+
+impl<const HZ: u32> TimerQueueBasedMonotonic for OsTimerMonotonic<HZ> {
+    type Backend = OsTimerBackend;
+    type Instant = fugit::Instant<u64, 1, HZ>;
+    type Duration = fugit::Duration<u64, 1, HZ>;
+}
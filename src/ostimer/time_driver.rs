@@ -0,0 +1,79 @@
+//! Embassy time driver on OSTIMER
+//!
+//! Enable a tick rate feature of `embassy-time` matching the OSTIMER clock,
+//! e.g. `tick-hz-1_000_000` for `clk_1m`.
+//!
+//! ```rust
+//! time_driver::init(ostimer);
+//! unsafe { cortex_m::peripheral::NVIC::unmask(interrupt::OS_EVENT) };
+//!
+//! #[interrupt]
+//! fn OS_EVENT() {
+//!     time_driver::on_interrupt();
+//! }
+//! ```
+
+use super::{clear_interrupt, now, regs, set_interrupt, write_alarm, OsTimer};
+use core::{cell::RefCell, task::Waker};
+use critical_section::{CriticalSection, Mutex};
+use embassy_time_driver::{Driver, TICK_HZ};
+use embassy_time_queue_utils::Queue;
+
+/// Alarms closer than this to the counter may be passed before the match
+/// register is synchronized.
+const MIN_DELTA: u64 = 4;
+
+struct OsTimerDriver {
+    queue: Mutex<RefCell<Queue>>,
+}
+
+embassy_time_driver::time_driver_impl!(static DRIVER: OsTimerDriver = OsTimerDriver {
+    queue: Mutex::new(RefCell::new(Queue::new())),
+});
+
+impl OsTimerDriver {
+    /// Set alarm at `at` ticks, return `false` if it already passed.
+    ///
+    /// With no alarm (`at == u64::MAX`) the interrupt still fires every half
+    /// counter period to extend the counter.
+    fn set_alarm(&self, at: u64) -> bool {
+        write_alarm(at);
+        set_interrupt(regs(), true);
+        now() + MIN_DELTA < at
+    }
+
+    fn rearm(&self, cs: CriticalSection) {
+        let mut queue = self.queue.borrow(cs).borrow_mut();
+        let mut next = queue.next_expiration(self.now());
+        while !self.set_alarm(next) {
+            next = queue.next_expiration(self.now());
+        }
+    }
+}
+
+impl Driver for OsTimerDriver {
+    fn now(&self) -> u64 {
+        now()
+    }
+
+    fn schedule_wake(&self, at: u64, waker: &Waker) {
+        critical_section::with(|cs| {
+            let updated = self.queue.borrow(cs).borrow_mut().schedule_wake(at, waker);
+            if updated {
+                self.rearm(cs);
+            }
+        })
+    }
+}
+
+/// Start the time driver, `timer` is kept running until reset.
+pub fn init(timer: OsTimer) {
+    assert_eq!(timer.clock() as u64, TICK_HZ);
+    critical_section::with(|cs| DRIVER.rearm(cs));
+}
+
+/// Call from `OS_EVENT` interrupt handler.
+pub fn on_interrupt() {
+    clear_interrupt(regs());
+    critical_section::with(|cs| DRIVER.rearm(cs));
+}
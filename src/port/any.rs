@@ -0,0 +1,117 @@
+//! Runtime pin ownership
+//!
+//! Board support code may read pin assignment at runtime. Hand a whole port
+//! over with `PortN::into_registry`, then claim its pins by index with
+//! [`AnyPin::take`].
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use crate::pac::{
+    common::{Reg, RW},
+    port::{regs::PCR, ADDRESSES, LEN},
+};
+
+use super::{pcr_methods, DriveStrength, PinConfig, Port, PortPin, PullValue, SlewRate};
+use crate::{consts::Dynamic, private::Sealed};
+
+/// Pins which can be claimed at runtime, per port.
+static AVAILABLE: [AtomicU32; LEN] = [const { AtomicU32::new(0) }; LEN];
+/// Pins currently claimed at runtime, per port.
+static TAKEN: [AtomicU32; LEN] = [const { AtomicU32::new(0) }; LEN];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortError {
+    /// Port or pin doesn't exist, or the port isn't handed to the registry.
+    InvalidPin,
+    /// Pin is already taken.
+    PinTaken,
+}
+
+/// Pin selected at runtime.
+///
+/// Configure it through [`Port`], its `PORT` and `PIN` are [`Dynamic`] so
+/// typed drivers don't accept it. A pin claimed with [`AnyPin::take`]
+/// releases its claim when dropped.
+pub struct AnyPin {
+    port: u8,
+    pin: u8,
+    claimed: bool,
+}
+
+impl AnyPin {
+    /// Claim `pin` of `port`.
+    pub fn take(port: u8, pin: u8) -> Result<Self, PortError> {
+        if port as usize >= LEN || pin >= 32 {
+            return Err(PortError::InvalidPin);
+        }
+        let mask = 1 << pin;
+        if AVAILABLE[port as usize].load(Ordering::Acquire) & mask == 0 {
+            return Err(PortError::InvalidPin);
+        }
+        if TAKEN[port as usize].fetch_or(mask, Ordering::AcqRel) & mask != 0 {
+            return Err(PortError::PinTaken);
+        }
+        Ok(Self {
+            port,
+            pin,
+            claimed: true,
+        })
+    }
+
+    /// Return `true` if `pin` of `port` is claimed.
+    pub fn is_taken(port: u8, pin: u8) -> bool {
+        port < LEN as u8
+            && pin < 32
+            && TAKEN[port as usize].load(Ordering::Acquire) & (1 << pin) != 0
+    }
+
+    /// Port number of the pin.
+    pub const fn port_number(&self) -> u8 {
+        self.port
+    }
+
+    /// Pin number in port.
+    pub const fn pin_number(&self) -> u8 {
+        self.pin
+    }
+
+    fn pcr(&self) -> Reg<PCR, RW> {
+        unsafe { crate::pac::port::PORT::from_ptr(ADDRESSES[self.port as usize] as _) }
+            .PCR(self.pin as usize)
+    }
+}
+
+impl Sealed for AnyPin {}
+impl Port for AnyPin {
+    type PORT = Dynamic;
+    type PIN = Dynamic;
+
+    pcr_methods!();
+}
+
+impl Drop for AnyPin {
+    fn drop(&mut self) {
+        if self.claimed {
+            TAKEN[self.port as usize].fetch_and(!(1 << self.pin), Ordering::AcqRel);
+        }
+    }
+}
+
+impl<const PORT: u8, const PIN: u8> From<PortPin<PORT, PIN>> for AnyPin {
+    /// Erase pin type, the pin stays outside the registry.
+    fn from(_pin: PortPin<PORT, PIN>) -> Self {
+        Self {
+            port: PORT,
+            pin: PIN,
+            claimed: false,
+        }
+    }
+}
+
+/// Make `pins` of `port` claimable at runtime, used by `PortN::into_registry`.
+pub(crate) fn register<const N: u8>(
+    _port: &crate::pac::Instance<crate::pac::port::PORT, N>,
+    pins: u32,
+) {
+    AVAILABLE[N as usize].fetch_or(pins, Ordering::AcqRel);
+}
@@ -0,0 +1,25 @@
+//! CLKOUT pin define
+
+use crate::port::Port;
+
+pub trait Pin: Port {
+    const MUX: u8;
+}
+
+pub fn prepare<P: Pin>(p: &mut P) {
+    p.floating();
+    p.analog(false);
+    p.set_mux(P::MUX);
+}
+
+// Used by pin tables in `pins/*.csv`, only `mcxa2` has CLKOUT rows yet.
+#[allow(unused_macros)]
+macro_rules! clkout {
+    (pin: $pin:ty, mux: $mux:expr) => {
+        impl crate::port::clkout::Pin for $pin {
+            const MUX: u8 = $mux;
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use clkout;
@@ -0,0 +1,69 @@
+//! CTIMER pin define
+
+use crate::{port::Port, private};
+
+pub trait Pin: Port {
+    type Module;
+    type Signal: Signal;
+    const MUX: u8;
+}
+
+/// CTIMER match output signal.
+pub trait Signal {
+    /// Match channel of the signal.
+    const CHANNEL: u8;
+}
+
+pub enum MAT0 {}
+pub enum MAT1 {}
+pub enum MAT2 {}
+pub enum MAT3 {}
+impl private::Sealed for MAT0 {}
+impl private::Sealed for MAT1 {}
+impl private::Sealed for MAT2 {}
+impl private::Sealed for MAT3 {}
+impl Signal for MAT0 {
+    const CHANNEL: u8 = 0;
+}
+impl Signal for MAT1 {
+    const CHANNEL: u8 = 1;
+}
+impl Signal for MAT2 {
+    const CHANNEL: u8 = 2;
+}
+impl Signal for MAT3 {
+    const CHANNEL: u8 = 3;
+}
+
+/// Pin with a CTIMER capture input function (`CT_INPn`), selected as capture
+/// source of any CTIMER channel through INPUTMUX.
+pub trait CapturePin: Port {
+    /// INPUTMUX `CTIMERn_CAPx` select value of the pin input.
+    const INPUT: u8;
+    const MUX: u8;
+}
+
+pub fn prepare<P: Pin>(p: &mut P) {
+    p.analog(false);
+    p.set_mux(P::MUX);
+}
+
+// Used by pin tables in `pins/*.csv`, which have no CTIMER rows yet.
+#[allow(unused_macros)]
+macro_rules! ctimer {
+    (pin: $pin:ty, module: $module:ident, signal: $signal:ident, mux: $mux:expr) => {
+        impl crate::port::ctimer::Pin for $pin {
+            type Module = crate::consts::$module;
+            type Signal = crate::port::ctimer::$signal;
+            const MUX: u8 = $mux;
+        }
+    };
+    (pin: $pin:ty, input: $input:literal, mux: $mux:expr) => {
+        impl crate::port::ctimer::CapturePin for $pin {
+            const INPUT: u8 = $input;
+            const MUX: u8 = $mux;
+        }
+    };
+}
+#[allow(unused_imports)]
+pub(crate) use ctimer;
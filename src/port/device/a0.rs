@@ -1,10 +1,11 @@
-use crate::port::PortPin;
+use crate::port::{PortGlobal, PortPin};
 use crate::private;
-use crate::syscon::{PeripheralCC, PeripheralRST};
+use crate::syscon::PeripheralGate;
 
-use crate::port::{lpuart, scg};
+use crate::port::{lpuart, port_global, scg};
 pub struct Port0 {
     _port: crate::pac::port::PORT0,
+    pub global: PortGlobal<0>,
     pub p0: PortPin<0, 0>,
     pub p1: PortPin<0, 1>,
     pub p2: PortPin<0, 2>,
@@ -18,10 +19,10 @@ unsafe impl Send for Port0 {}
 unsafe impl Sync for Port0 {}
 impl Port0 {
     pub fn new(mut port: crate::pac::port::PORT0) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<0, 0>::new() },
             p1: unsafe { PortPin::<0, 1>::new() },
             p2: unsafe { PortPin::<0, 2>::new() },
@@ -31,9 +32,16 @@ impl Port0 {
             p17: unsafe { PortPin::<0, 17>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT0 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port1 {
     _port: crate::pac::port::PORT1,
+    pub global: PortGlobal<1>,
     pub p0: PortPin<1, 0>,
     pub p1: PortPin<1, 1>,
     pub p2: PortPin<1, 2>,
@@ -57,10 +65,10 @@ unsafe impl Send for Port1 {}
 unsafe impl Sync for Port1 {}
 impl Port1 {
     pub fn new(mut port: crate::pac::port::PORT1) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<1, 0>::new() },
             p1: unsafe { PortPin::<1, 1>::new() },
             p2: unsafe { PortPin::<1, 2>::new() },
@@ -80,9 +88,16 @@ impl Port1 {
             p31: unsafe { PortPin::<1, 31>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT1 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port2 {
     _port: crate::pac::port::PORT2,
+    pub global: PortGlobal<2>,
     pub p0: PortPin<2, 0>,
     pub p1: PortPin<2, 1>,
     pub p2: PortPin<2, 2>,
@@ -103,10 +118,10 @@ unsafe impl Send for Port2 {}
 unsafe impl Sync for Port2 {}
 impl Port2 {
     pub fn new(mut port: crate::pac::port::PORT2) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<2, 0>::new() },
             p1: unsafe { PortPin::<2, 1>::new() },
             p2: unsafe { PortPin::<2, 2>::new() },
@@ -123,9 +138,16 @@ impl Port2 {
             p21: unsafe { PortPin::<2, 21>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT2 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port3 {
     _port: crate::pac::port::PORT3,
+    pub global: PortGlobal<3>,
     pub p0: PortPin<3, 0>,
     pub p1: PortPin<3, 1>,
     pub p6: PortPin<3, 6>,
@@ -149,10 +171,10 @@ unsafe impl Send for Port3 {}
 unsafe impl Sync for Port3 {}
 impl Port3 {
     pub fn new(mut port: crate::pac::port::PORT3) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<3, 0>::new() },
             p1: unsafe { PortPin::<3, 1>::new() },
             p6: unsafe { PortPin::<3, 6>::new() },
@@ -172,24 +194,17 @@ impl Port3 {
             p31: unsafe { PortPin::<3, 31>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT3 {
+        self._port.release();
+        self._port
+    }
 }
-lpuart!(pin: PortPin<0, 2>, module: U0, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<0, 3>, module: U0, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<1, 12>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<1, 13>, module: U2, signal: TXD, mux: 3);
-scg!(pin: PortPin<1, 30>, module: U0, signal: XTAL48M);
-scg!(pin: PortPin<1, 31>, module: U0, signal: EXTAL48M);
-lpuart!(pin: PortPin<1, 4>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<1, 5>, module: U2, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<1, 8>, module: U1, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<1, 9>, module: U1, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<2, 0>, module: U0, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<2, 1>, module: U0, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<2, 12>, module: U1, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<2, 13>, module: U1, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<2, 2>, module: U2, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<2, 3>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 14>, module: U2, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<3, 15>, module: U2, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<3, 8>, module: U1, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 9>, module: U1, signal: TXD, mux: 3);
+port_global!(Port0, 0, pins: 0x0003_004f);
+port_global!(Port1, 1, pins: 0xe000_3fff);
+port_global!(Port2, 2, pins: 0x0033_30ff);
+port_global!(Port3, 3, pins: 0xf800_ffc3);
+
+// Pin functions generated from `pins/*.csv` by build.rs
+include!(concat!(env!("OUT_DIR"), "/pin_functions.rs"));
@@ -1,9 +1,10 @@
 use crate::port::*;
 use crate::private;
-use crate::syscon::{PeripheralCC, PeripheralRST};
+use crate::syscon::PeripheralGate;
 
 pub struct Port0 {
     _port: crate::pac::port::PORT0,
+    pub global: PortGlobal<0>,
     pub p0: PortPin<0, 0>,
     pub p1: PortPin<0, 1>,
     pub p2: PortPin<0, 2>,
@@ -34,10 +35,10 @@ unsafe impl Send for Port0 {}
 unsafe impl Sync for Port0 {}
 impl Port0 {
     pub fn new(mut port: crate::pac::port::PORT0) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<0, 0>::new() },
             p1: unsafe { PortPin::<0, 1>::new() },
             p2: unsafe { PortPin::<0, 2>::new() },
@@ -64,9 +65,16 @@ impl Port0 {
             p27: unsafe { PortPin::<0, 27>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT0 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port1 {
     _port: crate::pac::port::PORT1,
+    pub global: PortGlobal<1>,
     pub p0: PortPin<1, 0>,
     pub p1: PortPin<1, 1>,
     pub p2: PortPin<1, 2>,
@@ -96,10 +104,10 @@ unsafe impl Send for Port1 {}
 unsafe impl Sync for Port1 {}
 impl Port1 {
     pub fn new(mut port: crate::pac::port::PORT1) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<1, 0>::new() },
             p1: unsafe { PortPin::<1, 1>::new() },
             p2: unsafe { PortPin::<1, 2>::new() },
@@ -125,9 +133,16 @@ impl Port1 {
             p31: unsafe { PortPin::<1, 31>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT1 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port2 {
     _port: crate::pac::port::PORT2,
+    pub global: PortGlobal<2>,
     pub p0: PortPin<2, 0>,
     pub p1: PortPin<2, 1>,
     pub p2: PortPin<2, 2>,
@@ -161,10 +176,10 @@ unsafe impl Send for Port2 {}
 unsafe impl Sync for Port2 {}
 impl Port2 {
     pub fn new(mut port: crate::pac::port::PORT2) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<2, 0>::new() },
             p1: unsafe { PortPin::<2, 1>::new() },
             p2: unsafe { PortPin::<2, 2>::new() },
@@ -194,9 +209,16 @@ impl Port2 {
             p26: unsafe { PortPin::<2, 26>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT2 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port3 {
     _port: crate::pac::port::PORT3,
+    pub global: PortGlobal<3>,
     pub p0: PortPin<3, 0>,
     pub p1: PortPin<3, 1>,
     pub p2: PortPin<3, 2>,
@@ -235,10 +257,10 @@ unsafe impl Send for Port3 {}
 unsafe impl Sync for Port3 {}
 impl Port3 {
     pub fn new(mut port: crate::pac::port::PORT3) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<3, 0>::new() },
             p1: unsafe { PortPin::<3, 1>::new() },
             p2: unsafe { PortPin::<3, 2>::new() },
@@ -273,9 +295,16 @@ impl Port3 {
             p31: unsafe { PortPin::<3, 31>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT3 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port4 {
     _port: crate::pac::port::PORT4,
+    pub global: PortGlobal<4>,
     pub p0: PortPin<4, 0>,
     pub p1: PortPin<4, 1>,
     pub p2: PortPin<4, 2>,
@@ -290,10 +319,10 @@ unsafe impl Send for Port4 {}
 unsafe impl Sync for Port4 {}
 impl Port4 {
     pub fn new(mut port: crate::pac::port::PORT4) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<4, 0>::new() },
             p1: unsafe { PortPin::<4, 1>::new() },
             p2: unsafe { PortPin::<4, 2>::new() },
@@ -304,52 +333,18 @@ impl Port4 {
             p7: unsafe { PortPin::<4, 7>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT4 {
+        self._port.release();
+        self._port
+    }
 }
-lpuart!(pin: PortPin<0, 2>, module: U0, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<0, 20>, module: U0, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<0, 21>, module: U0, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<0, 24>, module: U5, signal: RXD, mux: 8);
-lpuart!(pin: PortPin<0, 25>, module: U5, signal: TXD, mux: 8);
-lpuart!(pin: PortPin<0, 3>, module: U0, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<1, 10>, module: U5, signal: TXD, mux: 8);
-lpuart!(pin: PortPin<1, 11>, module: U5, signal: RXD, mux: 8);
-lpuart!(pin: PortPin<1, 12>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<1, 13>, module: U2, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<1, 16>, module: U5, signal: RXD, mux: 8);
-lpuart!(pin: PortPin<1, 17>, module: U5, signal: TXD, mux: 8);
-scg!(pin: PortPin<1, 30>, module: U0, signal: XTAL48M);
-scg!(pin: PortPin<1, 31>, module: U0, signal: EXTAL48M);
-lpuart!(pin: PortPin<1, 4>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<1, 5>, module: U2, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<1, 8>, module: U1, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<1, 9>, module: U1, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<2, 0>, module: U0, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<2, 1>, module: U0, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<2, 10>, module: U2, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<2, 11>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<2, 12>, module: U1, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<2, 13>, module: U1, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<2, 2>, module: U2, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<2, 3>, module: U2, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<2, 6>, module: U4, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<2, 7>, module: U4, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<3, 0>, module: U3, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 1>, module: U3, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<3, 10>, module: U5, signal: TXD, mux: 8);
-lpuart!(pin: PortPin<3, 11>, module: U5, signal: RXD, mux: 8);
-lpuart!(pin: PortPin<3, 12>, module: U3, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<3, 13>, module: U3, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 14>, module: U2, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<3, 15>, module: U2, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<3, 18>, module: U4, signal: RXD, mux: 2);
-lpuart!(pin: PortPin<3, 19>, module: U4, signal: TXD, mux: 2);
-lpuart!(pin: PortPin<3, 20>, module: U1, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 21>, module: U1, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<3, 27>, module: U4, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<3, 28>, module: U4, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 8>, module: U1, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<3, 9>, module: U1, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<4, 2>, module: U3, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<4, 3>, module: U4, signal: TXD, mux: 3);
-lpuart!(pin: PortPin<4, 4>, module: U4, signal: RXD, mux: 3);
-lpuart!(pin: PortPin<4, 5>, module: U3, signal: TXD, mux: 3);
+port_global!(Port0, 0, pins: 0x0fff_f0ff);
+port_global!(Port1, 1, pins: 0xe00f_ffff);
+port_global!(Port2, 2, pins: 0x07ff_ffff);
+port_global!(Port3, 3, pins: 0xffff_ffff);
+port_global!(Port4, 4, pins: 0x0000_00ff);
+
+// Pin functions generated from `pins/*.csv` by build.rs
+include!(concat!(env!("OUT_DIR"), "/pin_functions.rs"));
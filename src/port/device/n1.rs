@@ -1,10 +1,11 @@
-use crate::port::PortPin;
+use crate::port::{PortGlobal, PortPin};
 use crate::private;
-use crate::syscon::{PeripheralCC, PeripheralRST};
+use crate::syscon::PeripheralGate;
 
-use crate::port::{lpuart, scg};
+use crate::port::{lpuart, port_global, scg};
 pub struct Port0 {
     _port: crate::pac::port::PORT0,
+    pub global: PortGlobal<0>,
     pub p0: PortPin<0, 0>,
     pub p1: PortPin<0, 1>,
     pub p2: PortPin<0, 2>,
@@ -35,10 +36,10 @@ unsafe impl Send for Port0 {}
 unsafe impl Sync for Port0 {}
 impl Port0 {
     pub fn new(mut port: crate::pac::port::PORT0) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<0, 0>::new() },
             p1: unsafe { PortPin::<0, 1>::new() },
             p2: unsafe { PortPin::<0, 2>::new() },
@@ -65,9 +66,16 @@ impl Port0 {
             p29: unsafe { PortPin::<0, 29>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT0 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port1 {
     _port: crate::pac::port::PORT1,
+    pub global: PortGlobal<1>,
     pub p0: PortPin<1, 0>,
     pub p1: PortPin<1, 1>,
     pub p2: PortPin<1, 2>,
@@ -96,10 +104,10 @@ unsafe impl Send for Port1 {}
 unsafe impl Sync for Port1 {}
 impl Port1 {
     pub fn new(mut port: crate::pac::port::PORT1) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<1, 0>::new() },
             p1: unsafe { PortPin::<1, 1>::new() },
             p2: unsafe { PortPin::<1, 2>::new() },
@@ -124,9 +132,16 @@ impl Port1 {
             p31: unsafe { PortPin::<1, 31>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT1 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port2 {
     _port: crate::pac::port::PORT2,
+    pub global: PortGlobal<2>,
     pub p0: PortPin<2, 0>,
     pub p1: PortPin<2, 1>,
     pub p2: PortPin<2, 2>,
@@ -145,10 +160,10 @@ unsafe impl Send for Port2 {}
 unsafe impl Sync for Port2 {}
 impl Port2 {
     pub fn new(mut port: crate::pac::port::PORT2) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<2, 0>::new() },
             p1: unsafe { PortPin::<2, 1>::new() },
             p2: unsafe { PortPin::<2, 2>::new() },
@@ -163,9 +178,16 @@ impl Port2 {
             p11: unsafe { PortPin::<2, 11>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT2 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port3 {
     _port: crate::pac::port::PORT3,
+    pub global: PortGlobal<3>,
     pub p0: PortPin<3, 0>,
     pub p1: PortPin<3, 1>,
     pub p2: PortPin<3, 2>,
@@ -192,10 +214,10 @@ unsafe impl Send for Port3 {}
 unsafe impl Sync for Port3 {}
 impl Port3 {
     pub fn new(mut port: crate::pac::port::PORT3) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<3, 0>::new() },
             p1: unsafe { PortPin::<3, 1>::new() },
             p2: unsafe { PortPin::<3, 2>::new() },
@@ -218,9 +240,16 @@ impl Port3 {
             p23: unsafe { PortPin::<3, 23>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT3 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port4 {
     _port: crate::pac::port::PORT4,
+    pub global: PortGlobal<4>,
     pub p0: PortPin<4, 0>,
     pub p1: PortPin<4, 1>,
     pub p2: PortPin<4, 2>,
@@ -247,10 +276,10 @@ unsafe impl Send for Port4 {}
 unsafe impl Sync for Port4 {}
 impl Port4 {
     pub fn new(mut port: crate::pac::port::PORT4) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<4, 0>::new() },
             p1: unsafe { PortPin::<4, 1>::new() },
             p2: unsafe { PortPin::<4, 2>::new() },
@@ -273,9 +302,16 @@ impl Port4 {
             p23: unsafe { PortPin::<4, 23>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT4 {
+        self._port.release();
+        self._port
+    }
 }
 pub struct Port5 {
     _port: crate::pac::port::PORT5,
+    pub global: PortGlobal<5>,
     pub p0: PortPin<5, 0>,
     pub p1: PortPin<5, 1>,
     pub p2: PortPin<5, 2>,
@@ -290,10 +326,10 @@ unsafe impl Send for Port5 {}
 unsafe impl Sync for Port5 {}
 impl Port5 {
     pub fn new(mut port: crate::pac::port::PORT5) -> Self {
-        port.reset();
-        port.enable_clock(true);
+        port.acquire();
         Self {
             _port: port,
+            global: unsafe { PortGlobal::new() },
             p0: unsafe { PortPin::<5, 0>::new() },
             p1: unsafe { PortPin::<5, 1>::new() },
             p2: unsafe { PortPin::<5, 2>::new() },
@@ -304,6 +340,19 @@ impl Port5 {
             p7: unsafe { PortPin::<5, 7>::new() },
         }
     }
+
+    /// Release the PORT, its clock is gated when the last user releases it.
+    pub fn release(mut self) -> crate::pac::port::PORT5 {
+        self._port.release();
+        self._port
+    }
 }
-scg!(pin: PortPin<1, 30>, module: U0, signal: XTAL48M);
-scg!(pin: PortPin<1, 31>, module: U0, signal: EXTAL48M);
+port_global!(Port0, 0, pins: 0x3fff_c0ff);
+port_global!(Port1, 1, pins: 0xc00f_ffff);
+port_global!(Port2, 2, pins: 0x0000_0fff);
+port_global!(Port3, 3, pins: 0x00f7_ffc7);
+port_global!(Port4, 4, pins: 0x00ff_f0ff);
+port_global!(Port5, 5, pins: 0x0000_00ff);
+
+// Pin functions generated from `pins/*.csv` by build.rs
+include!(concat!(env!("OUT_DIR"), "/pin_functions.rs"));
@@ -0,0 +1,99 @@
+//! Port wide pin configuration with global pin control registers
+
+use crate::{
+    pac::port::{Instance, PORT},
+    port::PinConfig,
+};
+
+/// Write PCR[15:0] of all pins in `mask` with GPCLR/GPCHR.
+pub fn set_config_masked(port: PORT, mask: u32, config: PinConfig) {
+    let pcr: crate::pac::port::regs::PCR = config.into();
+    let value = pcr.0 & 0xffff;
+    let low = mask & 0xffff;
+    let high = mask >> 16;
+    if low != 0 {
+        port.GPCLR().write(|r| r.0 = (low << 16) | value);
+    }
+    if high != 0 {
+        port.GPCHR().write(|r| r.0 = (high << 16) | value);
+    }
+}
+
+/// Set LK of all pins in `mask`, keeping their configuration.
+pub fn lock_masked(port: PORT, mut mask: u32) {
+    while mask != 0 {
+        let pin = mask.trailing_zeros() as usize;
+        mask &= mask - 1;
+        port.PCR(pin).modify(|r| r.set_LK(true));
+    }
+}
+
+/// Port wide operations of port `N`.
+///
+/// Held by the `global` field of `PortN`, move it out with the pins to keep
+/// using it after the pins are split up.
+pub struct PortGlobal<const N: u8> {
+    _private: (),
+}
+
+impl<const N: u8> PortGlobal<N> {
+    /// # Safety
+    ///
+    /// Only one instance per port, created with its `PortN`.
+    pub(crate) const unsafe fn new() -> Self {
+        Self { _private: () }
+    }
+
+    pub(crate) fn regs(&self) -> PORT {
+        unsafe { Instance::<N>::instance() }.regs()
+    }
+}
+
+macro_rules! port_global {
+    ($port:ident, $n:literal, pins: $pins:literal) => {
+        impl crate::port::PortGlobal<$n> {
+            /// Mask of pins available on this port.
+            pub const PINS: u32 = $pins;
+
+            /// Apply `config` to all pins in `mask` with one register write per half port.
+            ///
+            /// This bypasses pin ownership, pins already moved out of the port are changed too.
+            pub fn set_config_masked(&mut self, mask: u32, config: crate::port::PinConfig) {
+                crate::port::global::set_config_masked(self.regs(), mask & Self::PINS, config);
+            }
+
+            /// Lock configuration of pins in `mask` until next system reset.
+            pub fn lock_masked(&mut self, mask: u32) {
+                crate::port::global::lock_masked(self.regs(), mask & Self::PINS);
+            }
+
+            /// Lock configuration of all pins until next system reset.
+            pub fn lock_all(&mut self) {
+                self.lock_masked(Self::PINS);
+            }
+
+            /// Save all pin configurations and GPIO direction and output data.
+            pub fn snapshot(&self, gpio: &crate::gpio::GPIO<$n>) -> crate::port::PortSnapshot<$n> {
+                crate::port::snapshot::capture(Self::PINS, self.regs(), gpio.regs())
+            }
+
+            /// Restore a snapshot taken by [`Self::snapshot`].
+            pub fn restore(
+                &mut self,
+                gpio: &mut crate::gpio::GPIO<$n>,
+                snapshot: &crate::port::PortSnapshot<$n>,
+            ) {
+                crate::port::snapshot::restore(snapshot, self.regs(), gpio.regs());
+            }
+        }
+
+        impl $port {
+            /// Hand all pins over to the runtime registry, claim them with [`crate::port::AnyPin::take`].
+            /// The PORT clock stays enabled.
+            pub fn into_registry(self) {
+                crate::port::any::register(&self._port, crate::port::PortGlobal::<$n>::PINS);
+            }
+        }
+    };
+}
+pub(crate) use port_global;
@@ -22,6 +22,7 @@ pub fn prepare<P: Pin>(p: &mut P) {
     p.set_mux(P::MUX);
 }
 
+// Used by pin tables in `pins/*.csv`.
 macro_rules! lpuart {
     (pin: $pin:ty, module: $module:ident, signal: $signal:ident, mux: $mux:expr) => {
         impl crate::port::lpuart::Pin for $pin {
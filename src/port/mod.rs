@@ -1,4 +1,11 @@
 //! PORT configuration and PIN constrain for NXP MCX Series MCUs.
+//!
+//! # Input filter
+//!
+//! MCX PORT has no digital glitch filter (no DFER/DFCR/DFWR registers like
+//! Kinetis PORT), only a per-pin passive analog filter. Enable it with
+//! [`Port::passive_filter`] or [`PinConfig::passive_filter`], and debounce
+//! further in software if needed.
 
 use crate::{
     consts::{Const, Unsigned},
@@ -9,11 +16,21 @@ use crate::{
     private::Sealed,
 };
 
+mod any;
+pub mod clkout;
+pub mod ctimer;
+mod global;
 pub mod lpuart;
 pub mod scg;
+mod snapshot;
 
+pub use any::{AnyPin, PortError};
+pub(crate) use global::port_global;
+pub use global::PortGlobal;
+#[allow(unused_imports)]
 pub(crate) use lpuart::lpuart;
 pub(crate) use scg::scg;
+pub use snapshot::PortSnapshot;
 
 /// Port trait for MCX N & A PORT peripheral.
 pub trait Port: Sealed {
@@ -30,7 +47,223 @@ pub trait Port: Sealed {
     fn pull(&mut self, up: bool);
     fn open_drain(&mut self, enable: bool);
     fn analog(&mut self, enable: bool);
+
+    fn set_pull_value(&mut self, v: PullValue);
+    fn set_drive_strength(&mut self, v: DriveStrength);
+    fn set_slew_rate(&mut self, v: SlewRate);
+    fn passive_filter(&mut self, enable: bool);
+    fn invert(&mut self, enable: bool);
+
+    /// Lock pin configuration until next system reset.
+    fn lock(&mut self);
+    fn is_locked(&self) -> bool;
+
+    /// Read back pin configuration.
+    fn config(&self) -> PinConfig;
+    /// Apply pin configuration in one register write.
+    fn set_config(&mut self, config: PinConfig);
+}
+
+/// Pin pull resistor selection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Pull {
+    #[default]
+    None,
+    Down,
+    Up,
+}
+
+/// Pin pull resistor value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PullValue {
+    #[default]
+    Low,
+    High,
+}
+
+/// Pin output drive strength.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DriveStrength {
+    #[default]
+    Normal,
+    High,
+}
+
+/// Pin output slew rate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SlewRate {
+    #[default]
+    Fast,
+    Slow,
+}
+
+/// Pin configuration, all PCR fields.
+///
+/// ```rust
+/// let config = PinConfig {
+///     pull: Pull::Up,
+///     passive_filter: true,
+///     ..Default::default()
+/// };
+/// port0.p1.set_config(config);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PinConfig {
+    pub mux: u8,
+    pub pull: Pull,
+    pub pull_value: PullValue,
+    pub drive_strength: DriveStrength,
+    pub slew_rate: SlewRate,
+    pub passive_filter: bool,
+    pub open_drain: bool,
+    /// Input buffer enable, disable it for analog function.
+    pub input_buffer: bool,
+    pub invert: bool,
+    /// Lock configuration until next system reset.
+    pub lock: bool,
+}
+
+impl Default for PinConfig {
+    fn default() -> Self {
+        Self {
+            mux: 0,
+            pull: Pull::None,
+            pull_value: PullValue::Low,
+            drive_strength: DriveStrength::Normal,
+            slew_rate: SlewRate::Fast,
+            passive_filter: false,
+            open_drain: false,
+            input_buffer: true,
+            invert: false,
+            lock: false,
+        }
+    }
+}
+
+impl From<PCR> for PinConfig {
+    fn from(r: PCR) -> Self {
+        Self {
+            mux: r.MUX(),
+            pull: match (r.PE(), r.PS()) {
+                (false, _) => Pull::None,
+                (true, false) => Pull::Down,
+                (true, true) => Pull::Up,
+            },
+            pull_value: if r.PV() {
+                PullValue::High
+            } else {
+                PullValue::Low
+            },
+            drive_strength: if r.DSE() {
+                DriveStrength::High
+            } else {
+                DriveStrength::Normal
+            },
+            slew_rate: if r.SRE() {
+                SlewRate::Slow
+            } else {
+                SlewRate::Fast
+            },
+            passive_filter: r.PFE(),
+            open_drain: r.ODE(),
+            input_buffer: r.IBE(),
+            invert: r.INV(),
+            lock: r.LK(),
+        }
+    }
+}
+
+impl From<PinConfig> for PCR {
+    fn from(c: PinConfig) -> Self {
+        let mut r = PCR(0);
+        r.set_MUX(c.mux);
+        r.set_PE(c.pull != Pull::None);
+        r.set_PS(c.pull == Pull::Up);
+        r.set_PV(c.pull_value == PullValue::High);
+        r.set_DSE(c.drive_strength == DriveStrength::High);
+        r.set_SRE(c.slew_rate == SlewRate::Slow);
+        r.set_PFE(c.passive_filter);
+        r.set_ODE(c.open_drain);
+        r.set_IBE(c.input_buffer);
+        r.set_INV(c.invert);
+        r.set_LK(c.lock);
+        r
+    }
+}
+
+/// PCR accessors shared by [`PortPin`] and [`AnyPin`], `self` provides `pcr()`.
+macro_rules! pcr_methods {
+    () => {
+        #[inline(always)]
+        fn mux(&self) -> u8 {
+            self.pcr().read().MUX()
+        }
+        #[inline(always)]
+        fn set_mux(&mut self, v: u8) {
+            self.pcr().modify(|r| r.set_MUX(v));
+        }
+
+        #[inline(always)]
+        fn floating(&mut self) {
+            self.pcr().modify(|r| r.set_PE(false));
+        }
+        #[inline(always)]
+        fn pull(&mut self, up: bool) {
+            self.pcr().modify(|r| {
+                r.set_PE(true);
+                r.set_PS(up);
+            })
+        }
+        #[inline(always)]
+        fn open_drain(&mut self, enable: bool) {
+            self.pcr().modify(|r| r.set_ODE(enable));
+        }
+        #[inline(always)]
+        fn analog(&mut self, enable: bool) {
+            self.pcr().modify(|r| r.set_IBE(!enable));
+        }
+
+        #[inline(always)]
+        fn set_pull_value(&mut self, v: PullValue) {
+            self.pcr().modify(|r| r.set_PV(v == PullValue::High));
+        }
+        #[inline(always)]
+        fn set_drive_strength(&mut self, v: DriveStrength) {
+            self.pcr().modify(|r| r.set_DSE(v == DriveStrength::High));
+        }
+        #[inline(always)]
+        fn set_slew_rate(&mut self, v: SlewRate) {
+            self.pcr().modify(|r| r.set_SRE(v == SlewRate::Slow));
+        }
+        #[inline(always)]
+        fn passive_filter(&mut self, enable: bool) {
+            self.pcr().modify(|r| r.set_PFE(enable));
+        }
+        #[inline(always)]
+        fn invert(&mut self, enable: bool) {
+            self.pcr().modify(|r| r.set_INV(enable));
+        }
+
+        #[inline(always)]
+        fn lock(&mut self) {
+            self.pcr().modify(|r| r.set_LK(true));
+        }
+        #[inline(always)]
+        fn is_locked(&self) -> bool {
+            self.pcr().read().LK()
+        }
+
+        #[inline(always)]
+        fn config(&self) -> PinConfig {
+            self.pcr().read().into()
+        }
+        #[inline(always)]
+        fn set_config(&mut self, config: PinConfig) {
+            self.pcr().write_value(config.into());
+        }
+    };
 }
+use pcr_methods;
 
 pub struct PortPin<const PORT: u8, const PIN: u8>;
 unsafe impl<const PORT: u8, const PIN: u8> Send for PortPin<PORT, PIN> {}
@@ -51,34 +284,7 @@ impl<const PORT: u8, const PIN: u8> Port for PortPin<PORT, PIN> {
     type PORT = Const<PORT>;
     type PIN = Const<PIN>;
 
-    #[inline(always)]
-    fn mux(&self) -> u8 {
-        self.pcr().read().MUX()
-    }
-    #[inline(always)]
-    fn set_mux(&mut self, v: u8) {
-        self.pcr().modify(|r| r.set_MUX(v));
-    }
-
-    #[inline(always)]
-    fn floating(&mut self) {
-        self.pcr().modify(|r| r.set_PE(false));
-    }
-    #[inline(always)]
-    fn pull(&mut self, up: bool) {
-        self.pcr().modify(|r| {
-            r.set_PE(true);
-            r.set_PS(up);
-        })
-    }
-    #[inline(always)]
-    fn open_drain(&mut self, enable: bool) {
-        self.pcr().modify(|r| r.set_ODE(enable));
-    }
-    #[inline(always)]
-    fn analog(&mut self, enable: bool) {
-        self.pcr().modify(|r| r.set_IBE(!enable));
-    }
+    pcr_methods!();
 }
 
 #[cfg(feature = "device")]
@@ -94,6 +300,12 @@ pub mod device {
         } else if #[cfg(feature = "mcxa2")] {
             mod a2;
             pub use a2::*;
+        } else if #[cfg(feature = "mcxn0")] {
+            mod n0;
+            pub use n0::*;
+        } else if #[cfg(feature = "mcxn1")] {
+            mod n1;
+            pub use n1::*;
         }
     }
 }
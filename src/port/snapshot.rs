@@ -0,0 +1,55 @@
+//! Port state snapshot
+
+use crate::pac::{gpio::GPIO, port::PORT};
+
+/// Saved PCRs of port `N` and PDDR/PDOR of its GPIO.
+///
+/// Capture pin configuration before reconfiguring pins for a low power mode,
+/// and restore it after wake up.
+///
+/// ```rust
+/// let snapshot = port1.global.snapshot(&gpio1);
+/// // Set pins to analog to cut leakage, enter low power mode with SPC ...
+/// port1.global.restore(&mut gpio1, &snapshot);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PortSnapshot<const N: u8> {
+    pins: u32,
+    pcr: [u32; 32],
+    pddr: u32,
+    pdor: u32,
+}
+
+impl<const N: u8> PortSnapshot<N> {
+    /// Port number of the snapshot.
+    pub const fn port(&self) -> u8 {
+        N
+    }
+}
+
+pub(crate) fn capture<const N: u8>(pins: u32, port: PORT, gpio: GPIO) -> PortSnapshot<N> {
+    let mut pcr = [0; 32];
+    for (n, v) in pcr.iter_mut().enumerate() {
+        if pins & (1 << n) != 0 {
+            *v = port.PCR(n).read().0;
+        }
+    }
+    PortSnapshot {
+        pins,
+        pcr,
+        pddr: gpio.PDDR().read().0,
+        pdor: gpio.PDOR().read().0,
+    }
+}
+
+pub(crate) fn restore<const N: u8>(snapshot: &PortSnapshot<N>, port: PORT, gpio: GPIO) {
+    // Output data first, so pins switched back to output don't glitch.
+    gpio.PDOR().write(|r| r.0 = snapshot.pdor);
+    for (n, v) in snapshot.pcr.iter().enumerate() {
+        // Locked PCRs ignore writes.
+        if snapshot.pins & (1 << n) != 0 && !port.PCR(n).read().LK() {
+            port.PCR(n).write(|r| r.0 = *v);
+        }
+    }
+    gpio.PDDR().write(|r| r.0 = snapshot.pddr);
+}
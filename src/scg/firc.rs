@@ -1,6 +1,9 @@
 //！Fast Internal Reference Clock
 
-use crate::{pac::scg::SCG, scg::SCGError};
+use crate::{
+    pac::scg::SCG,
+    scg::{unlock_trim, SCGError},
+};
 
 #[cfg(any(feature = "mcxa0", feature = "mcxa1"))]
 #[derive(Debug, Clone, Copy, Default)]
@@ -24,6 +27,54 @@ pub enum FIRC {
     FIRC180M = 7,
 }
 
+#[cfg(feature = "mcxn")]
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(u8)]
+pub enum FIRC {
+    #[default]
+    FIRC48M = 0,
+    FIRC144M = 1,
+}
+
+/// FIRC trim value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FircTrimValue {
+    pub coarse: u8,
+    pub fine: u8,
+}
+
+/// FIRC auto trim source.
+#[cfg(not(feature = "mcxa2"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FircTrimSource {
+    /// USB0 start of frame, 1 kHz.
+    USBSOF,
+    /// SOSC divided by `div`, `div` is written to TRIMDIV.
+    SOSC { div: u8 },
+}
+
+/// FIRC trim configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FircTrim {
+    /// Write trim value directly, auto trim is disabled.
+    Manual(FircTrimValue),
+    /// Trim FIRC continuously against a trim source.
+    #[cfg(not(feature = "mcxa2"))]
+    Auto(FircTrimSource),
+}
+
+impl FircTrim {
+    pub const fn valid(&self) -> bool {
+        match self {
+            FircTrim::Manual(v) => v.coarse <= 0x3F,
+            #[cfg(not(feature = "mcxa2"))]
+            FircTrim::Auto(FircTrimSource::SOSC { div }) => *div <= 0x7F,
+            #[cfg(not(feature = "mcxa2"))]
+            FircTrim::Auto(FircTrimSource::USBSOF) => true,
+        }
+    }
+}
+
 impl FIRC {
     pub const fn freq(&self) -> u32 {
         #[cfg(any(feature = "mcxa0", feature = "mcxa1"))]
@@ -40,6 +91,11 @@ impl FIRC {
             Self::FIRC90M => 90_000_000,
             Self::FIRC180M => 180_000_000,
         }
+        #[cfg(feature = "mcxn")]
+        match self {
+            Self::FIRC48M => 48_000_000,
+            Self::FIRC144M => 144_000_000,
+        }
     }
 
     pub(crate) fn enable(
@@ -49,7 +105,10 @@ impl FIRC {
         fclk_en: bool,
         sclk_en: bool,
     ) -> Result<(), SCGError> {
+        #[cfg(feature = "mcxa")]
         scg.FIRCCFG().write(|r| r.set_FREQ_SEL(firc as u8));
+        #[cfg(feature = "mcxn")]
+        scg.FIRCCFG().write(|r| r.set_RANGE(firc as u8 != 0));
 
         scg.FIRCCSR().modify(|r| r.set_LK(false));
         scg.FIRCCSR().modify(|r| {
@@ -68,6 +127,66 @@ impl FIRC {
         Ok(())
     }
 
+    pub(crate) fn trim(scg: SCG, trim: FircTrim) {
+        match trim {
+            FircTrim::Manual(v) => {
+                #[cfg(not(feature = "mcxa2"))]
+                {
+                    scg.FIRCCSR().modify(|r| r.set_LK(false));
+                    scg.FIRCCSR().modify(|r| {
+                        r.set_FIRCTREN(false);
+                        r.set_FIRCTRUP(false);
+                    });
+                    scg.FIRCCSR().modify(|r| r.set_LK(true));
+                }
+                unlock_trim(scg, || {
+                    scg.FIRCTRIM().modify(|r| {
+                        r.set_TRIMCOAR(v.coarse);
+                        r.set_TRIMFINE(v.fine);
+                    })
+                });
+            }
+            #[cfg(not(feature = "mcxa2"))]
+            FircTrim::Auto(source) => {
+                scg.FIRCCSR().modify(|r| r.set_LK(false));
+                scg.FIRCTCFG().write(|r| match source {
+                    FircTrimSource::USBSOF => r.set_TRIMSRC(0),
+                    FircTrimSource::SOSC { div } => {
+                        r.set_TRIMSRC(2);
+                        r.set_TRIMDIV(div);
+                    }
+                });
+                scg.FIRCCSR().modify(|r| {
+                    r.set_FIRCTREN(true);
+                    r.set_FIRCTRUP(true);
+                });
+                scg.FIRCCSR().modify(|r| r.set_LK(true));
+            }
+        }
+    }
+
+    /// Current trim value, including the one updated by auto trim.
+    pub(crate) fn trim_value(scg: SCG) -> FircTrimValue {
+        #[cfg(not(feature = "mcxa2"))]
+        let (coarse, fine) = {
+            let stat = scg.FIRCSTAT().read();
+            (stat.TRIMCOAR(), stat.TRIMFINE())
+        };
+        #[cfg(feature = "mcxa2")]
+        let (coarse, fine) = {
+            let trim = scg.FIRCTRIM().read();
+            (trim.TRIMCOAR(), trim.TRIMFINE())
+        };
+        FircTrimValue { coarse, fine }
+    }
+
+    pub(crate) fn trim_locked(scg: SCG) -> bool {
+        #[cfg(not(feature = "mcxa2"))]
+        return scg.FIRCCSR().read().TRIM_LOCK();
+        #[cfg(feature = "mcxa2")]
+        return scg.FIRCCSR().read().FIRCACC();
+    }
+
     pub(crate) fn disable(scg: SCG) -> Result<(), SCGError> {
         if scg.FIRCCSR().read().FIRCSEL() {
             return Err(SCGError::FIRCBusy);
@@ -31,10 +31,20 @@
 //!                                           └──────┘                               
 
 mod firc;
+#[cfg(any(feature = "mcxa2", feature = "mcxn"))]
 mod pll;
+#[cfg(feature = "mcxn")]
+mod rosc;
+mod sirc;
 mod sosc;
-pub use firc::FIRC;
+#[cfg(not(feature = "mcxa2"))]
+pub use firc::FircTrimSource;
+pub use firc::{FircTrim, FircTrimValue, FIRC};
+#[cfg(any(feature = "mcxa2", feature = "mcxn"))]
 pub use pll::*;
+#[cfg(feature = "mcxn")]
+pub use rosc::{RoscCapTrim, ROSC, ROSC_FREQ};
+pub use sirc::{SircTrim, SircTrimSource, SircTrimValue};
 pub use sosc::SOSC;
 
 use crate::{
@@ -57,6 +67,9 @@ pub enum SCGError {
     FIRCBusy,
     FIRCError,
 
+    ROSCBusy,
+    ROSCError,
+
     SPLLBusy,
 
     InvalidConfig,
@@ -68,6 +81,7 @@ pub enum MainClockSource {
     SIRC = 2,
     #[default]
     FIRC = 3,
+    #[cfg(feature = "mcxn")]
     ROSC = 4,
     APLL = 5,
     SPLL = 6,
@@ -77,12 +91,12 @@ pub enum MainClockSource {
 impl MainClockSource {
     pub const fn valid(&self) -> bool {
         match self {
-            MainClockSource::SOSC
-            | MainClockSource::SIRC
-            | MainClockSource::FIRC
-            | MainClockSource::ROSC => true,
+            MainClockSource::SOSC | MainClockSource::SIRC | MainClockSource::FIRC => true,
+
+            #[cfg(feature = "mcxn")]
+            MainClockSource::ROSC => true,
 
-            #[cfg(feature = "mcxa2")]
+            #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
             MainClockSource::SPLL => true,
 
             _ => false,
@@ -97,13 +111,30 @@ pub struct Config {
 
     pub sirc_12m_clk_en: bool,
     pub sirc_stop_en: bool,
+    /// SIRC trim configuration, `None` keeps the factory trim.
+    pub sirc_trim: Option<SircTrim>,
 
     pub firc: Option<FIRC>,
     pub firc_sclk_en: bool,
     pub firc_fclk_en: bool,
     pub firc_stop_en: bool,
+    /// FIRC trim configuration, `None` keeps the factory trim.
+    pub firc_trim: Option<FircTrim>,
 
+    /// ROSC configuration.
+    ///
+    /// `None` leaves the oscillator as it is, because it lives in the VBAT domain
+    /// and may be kept running across resets for the RTC.
+    #[cfg(feature = "mcxn")]
+    pub rosc: Option<ROSC>,
+    /// Also enable the 32 kHz clock to VDD_CORE domain modules (VBAT OSCCLKE
+    /// CLKE bit 2), SCG always gets it through the VSYS domain.
+    #[cfg(feature = "mcxn")]
+    pub rosc_core_clk_en: bool,
+
+    #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
     pub spll: Option<PllConfig>,
+    #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
     pub spll_stop_en: bool,
 
     pub main_clock_source: MainClockSource,
@@ -116,13 +147,22 @@ impl Default for Config {
 
             sirc_12m_clk_en: true,
             sirc_stop_en: false,
+            sirc_trim: None,
 
             firc: Some(FIRC::default()),
             firc_sclk_en: true,
             firc_fclk_en: true,
             firc_stop_en: false,
+            firc_trim: None,
 
+            #[cfg(feature = "mcxn")]
+            rosc: None,
+            #[cfg(feature = "mcxn")]
+            rosc_core_clk_en: false,
+
+            #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
             spll: None,
+            #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
             spll_stop_en: false,
 
             main_clock_source: MainClockSource::default(),
@@ -144,6 +184,39 @@ impl Config {
         if !self.firc_fclk_en && matches!(self.main_clock_source, MainClockSource::FIRC) {
             return false;
         }
+        #[cfg(feature = "mcxn")]
+        if self.rosc.is_none() && matches!(self.main_clock_source, MainClockSource::ROSC) {
+            return false;
+        }
+        #[cfg(feature = "mcxn")]
+        if let Some(rosc) = self.rosc {
+            if !rosc.valid() {
+                return false;
+            }
+        }
+        if let Some(trim) = self.sirc_trim {
+            if !trim.valid() {
+                return false;
+            }
+            if self.sosc.is_none() && matches!(trim, SircTrim::Auto(SircTrimSource::SOSC { .. })) {
+                return false;
+            }
+        }
+        if let Some(trim) = self.firc_trim {
+            if !trim.valid() || self.firc.is_none() {
+                return false;
+            }
+            #[cfg(not(feature = "mcxa2"))]
+            if self.sosc.is_none() && matches!(trim, FircTrim::Auto(FircTrimSource::SOSC { .. })) {
+                return false;
+            }
+        }
+        #[cfg(feature = "mcxn")]
+        if let Some(spll) = self.spll {
+            if self.rosc.is_none() && matches!(spll.source, PllSource::ROSC) {
+                return false;
+            }
+        }
 
         true
     }
@@ -194,18 +267,9 @@ impl<PINS> SCG<PINS> {
         }
 
         // configure SIRC
-        if !config.sirc_12m_clk_en && self.scg.regs().SIRCCSR().read().SIRCSEL() {
-            return Err(SCGError::SIRCBusy);
-        }
-        self.scg.regs().SIRCCSR().modify(|r| r.set_LK(false));
-        self.scg.regs().SIRCCSR().modify(|r| {
-            r.set_SIRCSTEN(config.sirc_stop_en);
-            r.set_SIRC_CLK_PERIPH_EN(config.sirc_12m_clk_en);
-            r.set_LK(true);
-        });
-        while !self.scg.regs().SIRCCSR().read().SIRCVLD() {}
-        if self.scg.regs().SIRCCSR().read().SIRCERR() {
-            return Err(SCGError::SIRCError);
+        sirc::enable(self.scg.regs(), config.sirc_stop_en, config.sirc_12m_clk_en)?;
+        if let Some(trim) = config.sirc_trim {
+            sirc::trim(self.scg.regs(), trim);
         }
 
         // configure FIRC
@@ -219,8 +283,18 @@ impl<PINS> SCG<PINS> {
             )?,
             None => FIRC::disable(self.scg.regs())?,
         }
+        if let Some(trim) = config.firc_trim {
+            FIRC::trim(self.scg.regs(), trim);
+        }
+
+        // configure ROSC
+        #[cfg(feature = "mcxn")]
+        if let Some(rosc) = config.rosc {
+            ROSC::enable(self.scg.regs(), rosc, config.rosc_core_clk_en)?;
+        }
 
         // configure SPLL
+        #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
         match config.spll {
             Some(spll) => PllConfig::enable_spll(self.scg.regs(), spll, config.spll_stop_en)?,
             None => PllConfig::disable_spll(self.scg.regs())?,
@@ -235,6 +309,72 @@ impl<PINS> SCG<PINS> {
 
         Ok(())
     }
+
+    /// Disable ROSC.
+    ///
+    /// [`SCG::freeze`] never disables ROSC, use this if the RTC no longer needs it.
+    #[cfg(feature = "mcxn")]
+    pub fn disable_rosc(&mut self) -> Result<(), SCGError> {
+        ROSC::disable(self.scg.regs())
+    }
+
+    /// Return `true` if ROSC is enabled and valid.
+    #[cfg(feature = "mcxn")]
+    pub fn rosc_valid(&self) -> bool {
+        self.scg.regs().ROSCCSR().read().ROSCVLD()
+    }
+
+    /// Return `true` if ROSC clock error is detected.
+    #[cfg(feature = "mcxn")]
+    pub fn rosc_error(&self) -> bool {
+        self.scg.regs().ROSCCSR().read().ROSCERR()
+    }
+
+    /// Return `true` if SIRC is enabled and valid.
+    pub fn sirc_valid(&self) -> bool {
+        self.scg.regs().SIRCCSR().read().SIRCVLD()
+    }
+
+    /// Return `true` if SIRC clock error is detected.
+    pub fn sirc_error(&self) -> bool {
+        self.scg.regs().SIRCCSR().read().SIRCERR()
+    }
+
+    /// Return current SIRC trim value.
+    pub fn sirc_trim(&self) -> SircTrimValue {
+        sirc::trim_value(self.scg.regs())
+    }
+
+    /// Return `true` if SIRC auto trim is locked to its trim source.
+    pub fn sirc_trim_locked(&self) -> bool {
+        sirc::trim_locked(self.scg.regs())
+    }
+
+    /// Return current FIRC trim value.
+    pub fn firc_trim(&self) -> FircTrimValue {
+        FIRC::trim_value(self.scg.regs())
+    }
+
+    /// Return `true` if FIRC is trimmed to its target frequency.
+    ///
+    /// On MCX A2 there is no auto trim, the FIRC accuracy flag is reported instead.
+    pub fn firc_trim_locked(&self) -> bool {
+        FIRC::trim_locked(self.scg.regs())
+    }
+}
+
+/// Unlock trim registers (FIRCTRIM, SIRCTRIM) while `f` is running.
+pub(crate) fn unlock_trim<R>(scg: crate::pac::scg::SCG, f: impl FnOnce() -> R) -> R {
+    scg.TRIM_LOCK().write(|r| {
+        r.set_TRIM_LOCK_KEY(0x5A5A);
+        r.set_TRIM_UNLOCK(true);
+    });
+    let ret = f();
+    scg.TRIM_LOCK().write(|r| {
+        r.set_TRIM_LOCK_KEY(0x5A5A);
+        r.set_TRIM_UNLOCK(false);
+    });
+    ret
 }
 
 pub struct Pins<EXTAL, XTAL>
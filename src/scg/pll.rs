@@ -21,6 +21,7 @@ pub enum PllSource {
     #[default]
     SOSC = 0,
     FIRC = 1,
+    #[cfg(feature = "mcxn")]
     ROSC = 2,
     SIRC = 3,
 }
@@ -0,0 +1,109 @@
+//! 32 kHz Oscillator Clock (ROSC)
+//!
+//! The 32 kHz oscillator lives in the VBAT domain. SCG only reports its status and
+//! selects it as clock source, the oscillator itself is configured via VBAT OSCCTLA.
+//!
+//! Only MCX N has the 32 kHz oscillator, MCX A VBAT only holds FRO16K and wakeup control.
+
+use crate::{
+    pac::{
+        scg::SCG,
+        vbat::{VBAT, VBAT0},
+    },
+    scg::SCGError,
+};
+
+/// ROSC frequency, always 32.768 kHz.
+pub const ROSC_FREQ: u32 = 32_768;
+
+/// ROSC load capacitor trim.
+///
+/// Each step adds 2 pF on the pin, from 0 pF (`0`) to 30 pF (`15`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoscCapTrim {
+    pub extal: u8,
+    pub xtal: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ROSC {
+    /// 32.768 kHz crystal on EXTAL32K/XTAL32K, with optional internal load capacitor.
+    Oscillator(Option<RoscCapTrim>),
+    /// 32.768 kHz square wave on EXTAL32K, oscillator is bypassed.
+    Bypass,
+}
+
+/// 32K clock to VSYS domain (SCG).
+const CLKE_VSYS: u8 = 1 << 0;
+/// 32K clock to VDD_CORE domain.
+const CLKE_VDD_CORE: u8 = 1 << 2;
+
+impl ROSC {
+    /// Return ROSC's frequency.
+    pub const fn freq(&self) -> u32 {
+        ROSC_FREQ
+    }
+
+    pub const fn valid(&self) -> bool {
+        match self {
+            ROSC::Oscillator(Some(trim)) => trim.extal <= 0xF && trim.xtal <= 0xF,
+            _ => true,
+        }
+    }
+
+    pub(crate) fn enable(scg: SCG, rosc: ROSC, core_clk_en: bool) -> Result<(), SCGError> {
+        let vbat = vbat();
+        vbat.OSCCTLA().write(|r| {
+            r.set_OSC_EN(true);
+            match rosc {
+                ROSC::Oscillator(Some(trim)) => {
+                    r.set_CAP_SEL_EN(true);
+                    r.set_EXTAL_CAP_SEL(trim.extal);
+                    r.set_XTAL_CAP_SEL(trim.xtal);
+                }
+                ROSC::Oscillator(None) => {}
+                ROSC::Bypass => r.set_OSC_BYP_EN(true),
+            }
+        });
+        // OSCCTLB must always hold the inverse of OSCCTLA
+        let ctl = vbat.OSCCTLA().read().0;
+        vbat.OSCCTLB().write(|r| r.set_INVERSE(!ctl));
+        while !vbat.STATUSA().read().OSC_RDY() {}
+
+        vbat.OSCCLKE().modify(|r| {
+            let clke = if core_clk_en {
+                r.CLKE() | CLKE_VSYS | CLKE_VDD_CORE
+            } else {
+                (r.CLKE() | CLKE_VSYS) & !CLKE_VDD_CORE
+            };
+            r.set_CLKE(clke);
+        });
+
+        while !scg.ROSCCSR().read().ROSCVLD() {}
+        if scg.ROSCCSR().read().ROSCERR() {
+            // ROSCERR is write 1 to clear
+            scg.ROSCCSR().modify(|r| r.set_ROSCERR(true));
+            return Err(SCGError::ROSCError);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn disable(scg: SCG) -> Result<(), SCGError> {
+        if scg.ROSCCSR().read().ROSCSEL() {
+            return Err(SCGError::ROSCBusy);
+        }
+
+        let vbat = vbat();
+        vbat.OSCCLKE()
+            .modify(|r| r.set_CLKE(r.CLKE() & !(CLKE_VSYS | CLKE_VDD_CORE)));
+        vbat.OSCCTLA().write(|r| r.0 = 0);
+        vbat.OSCCTLB().write(|r| r.set_INVERSE(!0));
+
+        Ok(())
+    }
+}
+
+fn vbat() -> VBAT {
+    unsafe { VBAT0::instance() }.regs()
+}
@@ -0,0 +1,104 @@
+//! Slow Internal Reference Clock
+
+#[cfg(not(feature = "mcxa0"))]
+use crate::scg::unlock_trim;
+use crate::{pac::scg::SCG, scg::SCGError};
+
+/// SIRC trim value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SircTrimValue {
+    pub cco: u8,
+    pub cl: u8,
+}
+
+/// SIRC auto trim source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SircTrimSource {
+    /// SOSC divided by `div`, `div` is written to TRIMDIV.
+    SOSC { div: u8 },
+}
+
+/// SIRC trim configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SircTrim {
+    /// Write trim value directly, auto trim is disabled.
+    ///
+    /// MCX A0 has no SIRCTRIM register.
+    #[cfg(not(feature = "mcxa0"))]
+    Manual(SircTrimValue),
+    /// Trim SIRC continuously against a trim source.
+    Auto(SircTrimSource),
+}
+
+impl SircTrim {
+    pub const fn valid(&self) -> bool {
+        match self {
+            #[cfg(not(feature = "mcxa0"))]
+            SircTrim::Manual(v) => v.cco <= 0x3F && v.cl <= 0x3F,
+            SircTrim::Auto(SircTrimSource::SOSC { div }) => *div <= 0x7F,
+        }
+    }
+}
+
+pub(crate) fn enable(scg: SCG, stop_en: bool, clk_en: bool) -> Result<(), SCGError> {
+    if !clk_en && scg.SIRCCSR().read().SIRCSEL() {
+        return Err(SCGError::SIRCBusy);
+    }
+    scg.SIRCCSR().modify(|r| r.set_LK(false));
+    scg.SIRCCSR().modify(|r| {
+        r.set_SIRCSTEN(stop_en);
+        r.set_SIRC_CLK_PERIPH_EN(clk_en);
+        r.set_LK(true);
+    });
+    while !scg.SIRCCSR().read().SIRCVLD() {}
+    if scg.SIRCCSR().read().SIRCERR() {
+        return Err(SCGError::SIRCError);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn trim(scg: SCG, trim: SircTrim) {
+    match trim {
+        #[cfg(not(feature = "mcxa0"))]
+        SircTrim::Manual(v) => {
+            scg.SIRCCSR().modify(|r| r.set_LK(false));
+            scg.SIRCCSR().modify(|r| {
+                r.set_SIRCTREN(false);
+                r.set_SIRCTRUP(false);
+            });
+            scg.SIRCCSR().modify(|r| r.set_LK(true));
+            unlock_trim(scg, || {
+                scg.SIRCTRIM().modify(|r| {
+                    r.set_CCOTRIM(v.cco);
+                    r.set_CLTRIM(v.cl);
+                })
+            });
+        }
+        SircTrim::Auto(SircTrimSource::SOSC { div }) => {
+            scg.SIRCCSR().modify(|r| r.set_LK(false));
+            scg.SIRCTCFG().write(|r| {
+                r.set_TRIMSRC(2);
+                r.set_TRIMDIV(div);
+            });
+            scg.SIRCCSR().modify(|r| {
+                r.set_SIRCTREN(true);
+                r.set_SIRCTRUP(true);
+            });
+            scg.SIRCCSR().modify(|r| r.set_LK(true));
+        }
+    }
+}
+
+/// Current trim value, including the one updated by auto trim.
+pub(crate) fn trim_value(scg: SCG) -> SircTrimValue {
+    let stat = scg.SIRCSTAT().read();
+    SircTrimValue {
+        cco: stat.CCOTRIM(),
+        cl: stat.CLTRIM(),
+    }
+}
+
+pub(crate) fn trim_locked(scg: SCG) -> bool {
+    scg.SIRCCSR().read().TRIM_LOCK()
+}
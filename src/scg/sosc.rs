@@ -18,7 +18,7 @@ impl SOSC {
     }
 
     pub(crate) fn enable(scg: SCG, sosc: SOSC, stopen: bool) -> Result<(), SCGError> {
-        #[cfg(feature = "mcxa2")]
+        #[cfg(any(feature = "mcxa2", feature = "mcxn"))]
         {
             scg.LDOCSR().modify(|r| r.set_LDOEN(true));
             while !scg.LDOCSR().read().VOUT_OK() {}
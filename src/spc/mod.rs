@@ -75,6 +75,28 @@ impl<const N: u8> SPC<N> {
         Ok(())
     }
 
+    /// Setup Active mode DCDC.
+    ///
+    /// On MCX N the CoreLDO is supplied by DCDC, raise DCDC before CoreLDO.
+    #[cfg(feature = "mcxn")]
+    pub fn set_active_dcdc(
+        &mut self,
+        voltage: Voltage,
+        strength: Strength,
+    ) -> Result<(), SPCError> {
+        if self.busy() {
+            return Err(SPCError::Busy);
+        }
+
+        self.spc.regs().ACTIVE_CFG().modify(|r| {
+            // 1: low drive strength, 2: normal drive strength
+            r.set_DCDC_VDD_DS(if bool::from(strength) { 2 } else { 1 });
+            r.set_DCDC_VDD_LVL(voltage as u8);
+        });
+        while self.busy() {}
+        Ok(())
+    }
+
     /// Setup SRAM operate voltage.
     ///
     /// `Voltage::UnderVoltage` is not supported.
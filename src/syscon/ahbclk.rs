@@ -0,0 +1,93 @@
+macro_rules! periph_ahbclk {
+    ( $( ( $(virt: $virt:ident)? $(periph: $periph:ty)?, $n:expr, $bit:expr $(, hRST: $hRST:expr)? $(, hCC: $hCC:expr)? $(, features: $features:meta)? ) )+ ) => {
+        $( periph_ahbclk!($(virt: $virt)? $(periph: $periph)?, $n, $bit $(, hRST: $hRST)? $(, hCC: $hCC)? $(, features: $features)?); )+
+    };
+
+    (virt: $virt:ident, $n:expr, $bit:expr $(, hRST: $hRST:expr)? $(, hCC: $hCC:expr)? $(, features: $features:meta)? ) => {
+        $(#[$features])?
+        pub struct $virt;
+        $(#[$features])?
+        impl crate::private::Sealed for $virt {}
+
+        $(#[$features])?
+        periph_ahbclk!(impl_rst $virt, $n, $bit $(,hRST: $hRST)?);
+        $(#[$features])?
+        periph_ahbclk!(impl_cc  $virt, $n, $bit $(,hCC: $hCC)?);
+        $(#[$features])?
+        periph_ahbclk!(impl_gate $virt $(,hRST: $hRST)? $(,hCC: $hCC)?);
+    };
+
+    (periph: $periph:ty, $n:expr, $bit:expr $(, hRST: $hRST:expr)? $(, hCC: $hCC:expr)? $(, features: $features:meta)? ) => {
+        $(#[$features])?
+        impl crate::private::Sealed for $periph {}
+
+        $(#[$features])?
+        periph_ahbclk!(impl_rst $periph, $n, $bit $(,hRST: $hRST)?);
+        $(#[$features])?
+        periph_ahbclk!(impl_cc  $periph, $n, $bit $(,hCC: $hCC)?);
+        $(#[$features])?
+        periph_ahbclk!(impl_gate $periph $(,hRST: $hRST)? $(,hCC: $hCC)?);
+    };
+
+    (impl_rst $name:ty, $n:expr, $bit:expr) => {};
+    (impl_rst $name:ty, $n:expr, $bit:expr, hRST: $hRST:expr) => {
+        impl crate::syscon::PeripheralRST for $name {
+            #[inline(always)]
+            fn assert_reset(&mut self, release: bool) {
+                // PRESETCTRL bit set means peripheral is held in reset
+                let regs = unsafe { crate::pac::syscon::SYSCON0::instance().regs() };
+                let reg = if release { regs.PRESETCTRLCLR($n) } else { regs.PRESETCTRLSET($n) };
+                reg.write(|r| *r = (1 << $bit));
+            }
+        }
+    };
+    (impl_gate $name:ty, hRST: $hRST:expr, hCC: $hCC:expr) => {
+        crate::syscon::periph_gate!($name);
+    };
+    (impl_gate $name:ty $(, $flag:ident: $value:expr)*) => {};
+    (impl_cc $name:ty, $n:expr, $bit:expr) => {};
+    (impl_cc $name:ty, $n:expr, $bit:expr, hCC: $hCC:expr) => {
+        impl crate::syscon::PeripheralCC for $name {
+            #[inline(always)]
+            fn enable_clock(&mut self, enable: bool) {
+                let regs = unsafe { crate::pac::syscon::SYSCON0::instance().regs() };
+                let reg = if enable { regs.AHBCLKCTRLSET($n) } else { regs.AHBCLKCTRLCLR($n) };
+                reg.write(|r| *r = (1 << $bit));
+            }
+        }
+    };
+}
+
+pub(crate) use periph_ahbclk;
+
+/// LPUARTn is the UART function of LP_FLEXCOMMn, it shares the reset, clock and
+/// users of the LP_FLEXCOMM.
+macro_rules! flexcomm_lpuart {
+    ($($lpuart:ident => $flexcomm:ident),+ $(,)?) => {
+        $(
+            impl crate::private::Sealed for crate::pac::lpuart::$lpuart {}
+            impl crate::syscon::PeripheralRST for crate::pac::lpuart::$lpuart {
+                #[inline(always)]
+                fn assert_reset(&mut self, release: bool) {
+                    let mut flexcomm = unsafe { crate::pac::lp_flexcomm::$flexcomm::instance() };
+                    crate::syscon::PeripheralRST::assert_reset(&mut flexcomm, release);
+                }
+            }
+            impl crate::syscon::PeripheralCC for crate::pac::lpuart::$lpuart {
+                #[inline(always)]
+                fn enable_clock(&mut self, enable: bool) {
+                    let mut flexcomm = unsafe { crate::pac::lp_flexcomm::$flexcomm::instance() };
+                    crate::syscon::PeripheralCC::enable_clock(&mut flexcomm, enable);
+                }
+            }
+            impl crate::syscon::PeripheralGate for crate::pac::lpuart::$lpuart {
+                #[inline(always)]
+                fn users() -> &'static core::sync::atomic::AtomicU8 {
+                    <crate::pac::lp_flexcomm::$flexcomm as crate::syscon::PeripheralGate>::users()
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use flexcomm_lpuart;
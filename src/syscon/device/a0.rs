@@ -1,7 +1,17 @@
+//! System Control definitions for A0 variant.
+//!
+//! Including
+//! - MCXA132
+//! - MCXA133
+//! - MCXA142
+//! - MCXA143
+//! - MCXA152
+//! - MCXA153
+
+use super::{generate_mrcc_clock_source, generate_mrcc_divider, periph_mrcc};
 use crate::pac::*;
-use crate::syscon::periph_en_define;
 
-periph_en_define! {
+periph_mrcc! {
     (periph: inputmux::INPUTMUX0, 0,  0, hRST: true, hCC: true, hACC: true)
     (periph: i3c::I3C0,           0,  1, hRST: true, hCC: true, hACC: true)
     (periph: ctimer::CTIMER0,     0,  2, hRST: true, hCC: true, hACC: true)
@@ -9,18 +19,20 @@ periph_en_define! {
     (periph: ctimer::CTIMER2,     0,  4, hRST: true, hCC: true, hACC: true)
     (periph: freqme::FREQME0,     0,  5, hRST: true, hCC: true, hACC: true)
     (periph: utick::UTICK0,       0,  6, hRST: true, hCC: true, hACC: true)
+    (periph: wwdt::WWDT0,         0,  7,             hCC: true, hACC: true)
     (periph: dma::DMA0,           0,  8, hRST: true, hCC: true, hACC: true)
     (periph: aoi::AOI0,           0,  9, hRST: true, hCC: true, hACC: true)
     (periph: crc::CRC0,           0, 10, hRST: true, hCC: true, hACC: true)
     (periph: eim::EIM0,           0, 11, hRST: true, hCC: true, hACC: true)
     (periph: erm::ERM0,           0, 12, hRST: true, hCC: true, hACC: true)
+    (periph: fmc::FMC0,           0, 13,             hCC: true, hACC: true)
     (periph: lpi2c::LPI2C0,       0, 16, hRST: true, hCC: true, hACC: true)
     (periph: lpspi::LPSPI0,       0, 17, hRST: true, hCC: true, hACC: true)
     (periph: lpspi::LPSPI1,       0, 18, hRST: true, hCC: true, hACC: true)
     (periph: lpuart::LPUART0,     0, 19, hRST: true, hCC: true, hACC: true)
     (periph: lpuart::LPUART1,     0, 20, hRST: true, hCC: true, hACC: true)
     (periph: lpuart::LPUART2,     0, 21, hRST: true, hCC: true, hACC: true)
-    (periph: usb::USB0,           0, 22, hRST: true, hCC: true, hACC: true)
+    (periph: usb::USB0,           0, 22, hRST: true, hCC: true, hACC: true, features: cfg(not(any(feature = "mcxa132", feature = "mcxa133"))))
     (periph: eqdc::QDC0,          0, 23, hRST: true, hCC: true, hACC: true)
     (periph: pwm::FLEXPWM0,       0, 24, hRST: true, hCC: true, hACC: true)
     (periph: ostimer::OSTIMER0,   0, 25, hRST: true, hCC: true, hACC: true)
@@ -39,3 +51,34 @@ periph_en_define! {
     (periph: gpio::GPIO3,         1,  8, hRST: true, hCC: true, hACC: true)
     (virt: ROMCP,                 1,  9,             hCC: true, hACC: true)
 }
+
+/// MRCC functional clock source.
+#[derive(Clone, Copy)]
+pub enum MRCCClockSource {
+    Fro12M = 0,
+    FroHfDiv = 2,
+    ClkIn = 3,
+    Clk1M = 5,
+    NoClock = 7,
+}
+
+macro_rules! lpuart_clock {
+    ($($n:literal),+) => {
+        paste::paste! {
+            $(
+                generate_mrcc_clock_source!(
+                    [<setup_lpuart $n _clock_source>],
+                    [<MRCC_LPUART $n _CLKSEL>],
+                    MRCCClockSource,
+                    concat!("Setup LPUART", $n, " clock source.")
+                );
+                generate_mrcc_divider!(
+                    [<setup_lpuart $n _divider>],
+                    [<MRCC_LPUART $n _CLKDIV>],
+                    concat!("Setup LPUART", $n, " divider.")
+                );
+            )+
+        }
+    };
+}
+lpuart_clock!(0, 1, 2);
@@ -1,7 +1,17 @@
+//! System Control definitions for A1 variant.
+//!
+//! Including
+//! - MCXA144
+//! - MCXA145
+//! - MCXA146
+//! - MCXA154
+//! - MCXA155
+//! - MCXA156
+
+use super::{generate_mrcc_clock_source, generate_mrcc_divider, periph_mrcc};
 use crate::pac::*;
-use crate::syscon::periph_en_define;
 
-periph_en_define! {
+periph_mrcc! {
     (periph: inputmux::INPUTMUX0, 0,  0, hRST: true, hCC: true, hACC: true)
     (periph: i3c::I3C0,           0,  1, hRST: true, hCC: true, hACC: true)
     (periph: ctimer::CTIMER0,     0,  2, hRST: true, hCC: true, hACC: true)
@@ -11,11 +21,13 @@ periph_en_define! {
     (periph: ctimer::CTIMER4,     0,  6, hRST: true, hCC: true, hACC: true)
     (periph: freqme::FREQME0,     0,  7, hRST: true, hCC: true, hACC: true)
     (periph: utick::UTICK0,       0,  8, hRST: true, hCC: true, hACC: true)
+    (periph: wwdt::WWDT0,         0,  9,             hCC: true, hACC: true)
     (periph: dma::DMA0,           0, 10, hRST: true, hCC: true, hACC: true)
     (periph: aoi::AOI0,           0, 11, hRST: true, hCC: true, hACC: true)
     (periph: crc::CRC0,           0, 12, hRST: true, hCC: true, hACC: true)
     (periph: eim::EIM0,           0, 13, hRST: true, hCC: true, hACC: true)
     (periph: erm::ERM0,           0, 14, hRST: true, hCC: true, hACC: true)
+    (periph: fmc::FMC0,           0, 15,             hCC: true, hACC: true)
     (periph: aoi::AOI1,           0, 16, hRST: true, hCC: true)
     (periph: flexio::FLEXIO0,     0, 17, hRST: true, hCC: true)
     (periph: lpi2c::LPI2C0,       0, 18, hRST: true, hCC: true, hACC: true)
@@ -29,17 +41,17 @@ periph_en_define! {
     (periph: lpuart::LPUART4,     0, 26, hRST: true, hCC: true, hACC: true)
     (periph: usb::USB0,           0, 27, hRST: true, hCC: true, hACC: true)
     (periph: eqdc::QDC0,          0, 28, hRST: true, hCC: true, hACC: true)
-    (periph: eqdc::QDC1,          0, 29, hRST: true, hCC: true, hACC: true)
+    (periph: eqdc::QDC1,          0, 29, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa154", feature = "mcxa155", feature = "mcxa156")))
     (periph: pwm::FLEXPWM0,       0, 30, hRST: true, hCC: true, hACC: true)
-    (periph: pwm::FLEXPWM1,       0, 31, hRST: true, hCC: true, hACC: true)
+    (periph: pwm::FLEXPWM1,       0, 31, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa154", feature = "mcxa155", feature = "mcxa156")))
 
     (periph: ostimer::OSTIMER0,   1,  0, hRST: true, hCC: true, hACC: true)
     (periph: adc::ADC0,           1,  1, hRST: true, hCC: true, hACC: true)
     (periph: adc::ADC1,           1,  2, hRST: true, hCC: true, hACC: true)
     (periph: lpcmp::CMP0,         1,  3,             hCC: true, hACC: true)
     (periph: lpcmp::CMP1,         1,  4, hRST: true, hCC: true, hACC: true)
-    (periph: lpdac::DAC0,         1,  5, hRST: true, hCC: true, hACC: true)
-    (periph: opamp::OPAMP0,       1,  6, hRST: true, hCC: true, hACC: true)
+    (periph: lpdac::DAC0,         1,  5, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa154", feature = "mcxa155", feature = "mcxa156")))
+    (periph: opamp::OPAMP0,       1,  6, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa154", feature = "mcxa155", feature = "mcxa156")))
     (periph: port::PORT0,         1,  7, hRST: true, hCC: true, hACC: true)
     (periph: port::PORT1,         1,  8, hRST: true, hCC: true, hACC: true)
     (periph: port::PORT2,         1,  9, hRST: true, hCC: true, hACC: true)
@@ -57,3 +69,34 @@ periph_en_define! {
     (periph: gpio::GPIO4,         1, 24, hRST: true, hCC: true, hACC: true)
     (virt: ROMC,                  1, 25,             hCC: true, hACC: true)
 }
+
+/// MRCC functional clock source.
+#[derive(Clone, Copy)]
+pub enum MRCCClockSource {
+    Fro12M = 0,
+    FroHfDiv = 2,
+    ClkIn = 3,
+    Clk1M = 5,
+    NoClock = 7,
+}
+
+macro_rules! lpuart_clock {
+    ($($n:literal),+) => {
+        paste::paste! {
+            $(
+                generate_mrcc_clock_source!(
+                    [<setup_lpuart $n _clock_source>],
+                    [<MRCC_LPUART $n _CLKSEL>],
+                    MRCCClockSource,
+                    concat!("Setup LPUART", $n, " clock source.")
+                );
+                generate_mrcc_divider!(
+                    [<setup_lpuart $n _divider>],
+                    [<MRCC_LPUART $n _CLKDIV>],
+                    concat!("Setup LPUART", $n, " divider.")
+                );
+            )+
+        }
+    };
+}
+lpuart_clock!(0, 1, 2, 3, 4);
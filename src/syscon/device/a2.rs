@@ -1,15 +1,17 @@
 //! System Control definitions for A2 variant.
 //!
 //! Including
+//! - MCXA255
+//! - MCXA256
 //! - MCXA345
 //! - MCXA346
 
-use super::periph_mrcc;
+use super::{generate_mrcc_clock_source, generate_mrcc_divider, periph_mrcc};
 use crate::pac::*;
 
 periph_mrcc! {
     (periph: inputmux::INPUTMUX0, 0,  0, hRST: true, hCC: true, hACC: true)
-    // (periph: i3c::I3C0,           0,  1, hRST: true, hCC: true, hACC: true)
+    (periph: i3c::I3C0,           0,  1, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
     (periph: ctimer::CTIMER0,     0,  2, hRST: true, hCC: true, hACC: true)
     (periph: ctimer::CTIMER1,     0,  3, hRST: true, hCC: true, hACC: true)
     (periph: ctimer::CTIMER2,     0,  4, hRST: true, hCC: true, hACC: true)
@@ -26,7 +28,7 @@ periph_mrcc! {
     (periph: erm::ERM0,           0, 15, hRST: true, hCC: true, hACC: true)
     (periph: fmc::FMC0,           0, 16,             hCC: true, hACC: true)
     (periph: aoi::AOI1,           0, 17, hRST: true, hCC: true, hACC: true)
-    // (periph: flexio::FLEXIO0,     0, 18, hRST: true, hCC: true, hACC: true)
+    (periph: flexio::FLEXIO0,     0, 18, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
     (periph: lpi2c::LPI2C0,       0, 19, hRST: true, hCC: true, hACC: true)
     (periph: lpi2c::LPI2C1,       0, 20, hRST: true, hCC: true, hACC: true)
     (periph: lpspi::LPSPI0,       0, 21, hRST: true, hCC: true, hACC: true)
@@ -36,7 +38,7 @@ periph_mrcc! {
     (periph: lpuart::LPUART2,     0, 25, hRST: true, hCC: true, hACC: true)
     (periph: lpuart::LPUART3,     0, 26, hRST: true, hCC: true, hACC: true)
     (periph: lpuart::LPUART4,     0, 27, hRST: true, hCC: true, hACC: true)
-    // (periph: usb::USB0,           0, 28, hRST: true, hCC: true, hACC: true)
+    (periph: usb::USB0,           0, 28, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
     (periph: eqdc::EQDC0,         0, 29, hRST: true, hCC: true, hACC: true)
     (periph: eqdc::EQDC1,         0, 30, hRST: true, hCC: true, hACC: true)
     (periph: pwm::FLEXPWM0,       0, 31, hRST: true, hCC: true, hACC: true)
@@ -47,12 +49,12 @@ periph_mrcc! {
     (periph: adc::ADC1,           1,  3, hRST: true, hCC: true, hACC: true)
     (periph: lpcmp::CMP0,         1,  4,             hCC: true, hACC: true)
     (periph: lpcmp::CMP1,         1,  5, hRST: true, hCC: true, hACC: true)
-    (periph: lpcmp::CMP2,         1,  6, hRST: true, hCC: true, hACC: true)
+    (periph: lpcmp::CMP2,         1,  6, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
     (periph: lpdac::DAC0,         1,  7, hRST: true, hCC: true, hACC: true)
     (periph: opamp::OPAMP0,       1,  8, hRST: true, hCC: true, hACC: true)
-    (periph: opamp::OPAMP1,       1,  9, hRST: true, hCC: true, hACC: true)
-    (periph: opamp::OPAMP2,       1, 10, hRST: true, hCC: true, hACC: true)
-    (periph: opamp::OPAMP3,       1, 11, hRST: true, hCC: true, hACC: true)
+    (periph: opamp::OPAMP1,       1,  9, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
+    (periph: opamp::OPAMP2,       1, 10, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
+    (periph: opamp::OPAMP3,       1, 11, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
     (periph: port::PORT0,         1, 12, hRST: true, hCC: true, hACC: true)
     (periph: port::PORT1,         1, 13, hRST: true, hCC: true, hACC: true)
     (periph: port::PORT2,         1, 14, hRST: true, hCC: true, hACC: true)
@@ -63,14 +65,14 @@ periph_mrcc! {
     // (periph: can::CAN1,           1, 19, hRST: true, hCC: true, hACC: true)
     (periph: lpi2c::LPI2C2,       1, 20, hRST: true, hCC: true, hACC: true)
     (periph: lpi2c::LPI2C3,       1, 21, hRST: true, hCC: true, hACC: true)
-    (periph: lpuart::LPUART5,     1, 22, hRST: true, hCC: true, hACC: true)
+    (periph: lpuart::LPUART5,     1, 22, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
     // (periph: tdet::TDET0,         1, 23,             hCC: true)
-    // (periph: pkc::PKC0,           1, 24, hRST: true, hCC: true, hACC: true)
-    // (periph: sgi::SGI0,           1, 25,             hCC: true, hACC: true)
-    // (periph: trng::TRNG0,         1, 26, hRST: true, hCC: true, hACC: true)
-    // (periph: udf::UDF0,           1, 27,             hCC: true, hACC: true)
-    (periph: adc::ADC2,           1, 28, hRST: true, hCC: true, hACC: true)
-    (periph: adc::ADC3,           1, 29, hRST: true, hCC: true, hACC: true)
+    (periph: pkc::PKC0,           1, 24, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
+    (periph: sgi::SGI0,           1, 25,             hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
+    (periph: trng::TRNG0,         1, 26, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
+    (periph: udf::UDF0,           1, 27,             hCC: true, hACC: true, features: cfg(any(feature = "mcxa255", feature = "mcxa256")))
+    (periph: adc::ADC2,           1, 28, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
+    (periph: adc::ADC3,           1, 29, hRST: true, hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
 
     (virt: RAMA,                  2,  1,             hCC: true, hACC: true)
     (virt: RAMB,                  2,  2,             hCC: true, hACC: true)
@@ -80,6 +82,38 @@ periph_mrcc! {
     (periph: gpio::GPIO2,         2,  6, hRST: true, hCC: true, hACC: true)
     (periph: gpio::GPIO3,         2,  7, hRST: true, hCC: true, hACC: true)
     (periph: gpio::GPIO4,         2,  8, hRST: true, hCC: true, hACC: true)
-    (periph: mau::MAU0,           2,  9,             hCC: true, hACC: true)
+    (periph: mau::MAU0,           2,  9,             hCC: true, hACC: true, features: cfg(any(feature = "mcxa345", feature = "mcxa346")))
     (virt: ROMC,                  2, 10,             hCC: true, hACC: true)
 }
+
+/// MRCC functional clock source.
+#[derive(Clone, Copy)]
+pub enum MRCCClockSource {
+    FroLfDiv = 0,
+    FroHfDiv = 2,
+    ClkIn = 3,
+    Clk1M = 5,
+    SPllDiv = 6,
+    NoClock = 7,
+}
+
+macro_rules! lpuart_clock {
+    ($($n:literal),+) => {
+        paste::paste! {
+            $(
+                generate_mrcc_clock_source!(
+                    [<setup_lpuart $n _clock_source>],
+                    [<MRCC_LPUART $n _CLKSEL>],
+                    MRCCClockSource,
+                    concat!("Setup LPUART", $n, " clock source.")
+                );
+                generate_mrcc_divider!(
+                    [<setup_lpuart $n _divider>],
+                    [<MRCC_LPUART $n _CLKDIV>],
+                    concat!("Setup LPUART", $n, " divider.")
+                );
+            )+
+        }
+    };
+}
+lpuart_clock!(0, 1, 2, 3, 4, 5);
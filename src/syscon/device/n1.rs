@@ -0,0 +1,110 @@
+//! System Control definitions for N1 variant.
+//!
+//! Including
+//! - MCXN235
+//! - MCXN236
+//!
+//! PORT5 and GPIO5 are in the always-on domain and have no SYSCON gate, their
+//! reset and clock control are no-op.
+
+use super::{flexcomm_lpuart, periph_ahbclk};
+use crate::pac::*;
+
+periph_ahbclk! {
+    (virt: ROM,                           0,  1,             hCC: true)
+    (virt: RAMB,                          0,  2,             hCC: true)
+    (virt: RAMC,                          0,  3,             hCC: true)
+    (virt: RAMD,                          0,  4,             hCC: true)
+    (virt: RAME,                          0,  5,             hCC: true)
+    (virt: RAMF,                          0,  6,             hCC: true)
+    (periph: fmu::FMU0,                   0,  9,             hCC: true)
+    (virt: FMC,                           0, 10,             hCC: true)
+    (periph: inputmux::INPUTMUX0,         0, 12, hRST: true, hCC: true)
+    (periph: port::PORT0,                 0, 13, hRST: true, hCC: true)
+    (periph: port::PORT1,                 0, 14, hRST: true, hCC: true)
+    (periph: port::PORT2,                 0, 15, hRST: true, hCC: true)
+    (periph: port::PORT3,                 0, 16, hRST: true, hCC: true)
+    (periph: port::PORT4,                 0, 17, hRST: true, hCC: true)
+    (periph: gpio::GPIO0,                 0, 19, hRST: true, hCC: true)
+    (periph: gpio::GPIO1,                 0, 20, hRST: true, hCC: true)
+    (periph: gpio::GPIO2,                 0, 21, hRST: true, hCC: true)
+    (periph: gpio::GPIO3,                 0, 22, hRST: true, hCC: true)
+    (periph: gpio::GPIO4,                 0, 23, hRST: true, hCC: true)
+    (periph: pint::PINT0,                 0, 25, hRST: true, hCC: true)
+    (periph: dma::DMA0,                   0, 26, hRST: true, hCC: true)
+    (periph: crc::CRC0,                   0, 27, hRST: true, hCC: true)
+    (periph: wwdt::WWDT0,                 0, 28,             hCC: true)
+    (periph: wwdt::WWDT1,                 0, 29,             hCC: true)
+
+    (periph: mrt::MRT0,                   1,  0, hRST: true, hCC: true)
+    (periph: ostimer::OSTIMER0,           1,  1, hRST: true, hCC: true)
+    (periph: adc::ADC0,                   1,  3, hRST: true, hCC: true)
+    (periph: adc::ADC1,                   1,  4, hRST: true, hCC: true)
+    (periph: rtc::RTC0,                   1,  6,             hCC: true)
+    (periph: utick::UTICK0,               1, 10, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM0,   1, 11, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM1,   1, 12, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM2,   1, 13, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM3,   1, 14, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM4,   1, 15, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM5,   1, 16, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM6,   1, 17, hRST: true, hCC: true)
+    (periph: lp_flexcomm::LP_FLEXCOMM7,   1, 18, hRST: true, hCC: true)
+    (periph: pdm::PDM0,                   1, 21, hRST: true, hCC: true)
+    (periph: ctimer::CTIMER2,             1, 22, hRST: true, hCC: true)
+    (periph: ctimer::CTIMER0,             1, 25, hRST: true, hCC: true)
+    (periph: ctimer::CTIMER1,             1, 26, hRST: true, hCC: true)
+    (periph: smartdma::SMARTDMA0,         1, 31, hRST: true, hCC: true)
+
+    (periph: dma::DMA1,                   2,  1, hRST: true, hCC: true)
+    (periph: flexio::FLEXIO0,             2,  4, hRST: true, hCC: true)
+    (periph: i2s::SAI0,                   2,  5, hRST: true, hCC: true)
+    (periph: i2s::SAI1,                   2,  6, hRST: true, hCC: true)
+    (periph: freqme::FREQME0,             2,  8, hRST: true, hCC: true)
+    (periph: can::CAN0,                   2, 14, hRST: true, hCC: true)
+    (periph: can::CAN1,                   2, 15, hRST: true, hCC: true)
+    (periph: ctimer::CTIMER3,             2, 21, hRST: true, hCC: true)
+    (periph: ctimer::CTIMER4,             2, 22, hRST: true, hCC: true)
+    (periph: puf::PUF0,                   2, 23, hRST: true, hCC: true)
+    (periph: pkc::PKC0,                   2, 24, hRST: true, hCC: true)
+
+    (periph: i3c::I3C0,                   3,  0, hRST: true, hCC: true)
+    (periph: i3c::I3C1,                   3,  1, hRST: true, hCC: true)
+    (periph: qdc::QDC0,                   3,  4, hRST: true, hCC: true)
+    (periph: qdc::QDC1,                   3,  5, hRST: true, hCC: true)
+    (periph: pwm::PWM0,                   3,  6, hRST: true, hCC: true)
+    (periph: pwm::PWM1,                   3,  7, hRST: true, hCC: true)
+    (periph: evtg::EVTG0,                 3,  8, hRST: true, hCC: true)
+    (periph: ewm::EWM0,                   3, 22,             hCC: true)
+    (periph: eim::EIM0,                   3, 23,             hCC: true)
+    (periph: erm::ERM0,                   3, 24,             hCC: true)
+    (periph: intm::INTM0,                 3, 25,             hCC: true)
+}
+
+flexcomm_lpuart! {
+    LPUART0 => LP_FLEXCOMM0,
+    LPUART1 => LP_FLEXCOMM1,
+    LPUART2 => LP_FLEXCOMM2,
+    LPUART3 => LP_FLEXCOMM3,
+    LPUART4 => LP_FLEXCOMM4,
+    LPUART5 => LP_FLEXCOMM5,
+    LPUART6 => LP_FLEXCOMM6,
+    LPUART7 => LP_FLEXCOMM7,
+}
+
+impl crate::private::Sealed for port::PORT5 {}
+impl crate::syscon::PeripheralRST for port::PORT5 {
+    fn assert_reset(&mut self, _release: bool) {}
+}
+impl crate::syscon::PeripheralCC for port::PORT5 {
+    fn enable_clock(&mut self, _enable: bool) {}
+}
+super::periph_gate!(port::PORT5);
+impl crate::private::Sealed for gpio::GPIO5 {}
+impl crate::syscon::PeripheralRST for gpio::GPIO5 {
+    fn assert_reset(&mut self, _release: bool) {}
+}
+impl crate::syscon::PeripheralCC for gpio::GPIO5 {
+    fn enable_clock(&mut self, _enable: bool) {}
+}
+super::periph_gate!(gpio::GPIO5);
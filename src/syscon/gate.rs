@@ -0,0 +1,15 @@
+//! Reference counted peripheral gate.
+
+macro_rules! periph_gate {
+    ($name:ty) => {
+        impl crate::syscon::PeripheralGate for $name {
+            #[inline(always)]
+            fn users() -> &'static core::sync::atomic::AtomicU8 {
+                static USERS: core::sync::atomic::AtomicU8 = core::sync::atomic::AtomicU8::new(0);
+                &USERS
+            }
+        }
+    };
+}
+
+pub(crate) use periph_gate;
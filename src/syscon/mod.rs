@@ -2,6 +2,8 @@
 //!
 //! This module provides the peripheral control for NXP MCX MCUs.
 
+use core::sync::atomic::{AtomicU8, Ordering};
+
 use crate::private::Sealed;
 
 /// Peripheral reset trait.
@@ -11,8 +13,8 @@ pub trait PeripheralRST: Sealed {
 
     /// Reset the peripheral.
     fn reset(&mut self) {
-        self.assert_reset(true);
         self.assert_reset(false);
+        self.assert_reset(true);
     }
 }
 
@@ -22,6 +24,61 @@ pub trait PeripheralCC: Sealed {
     fn enable_clock(&mut self, enable: bool);
 }
 
+/// Peripheral automatic clock gating trait.
+///
+/// Only available on MCX A, the clock of an idle peripheral is gated by hardware
+/// when automatic clock gating is enabled.
+pub trait PeripheralACC: Sealed {
+    /// Enable or disable automatic clock gating.
+    fn enable_auto_clock_gating(&mut self, enable: bool);
+}
+
+/// Reference counted peripheral gate.
+///
+/// Peripherals like PORT, GPIO and INPUTMUX are shared by several drivers. The
+/// peripheral is reset and clocked only when the first user acquires it, and its
+/// clock is gated when the last user releases it.
+///
+/// The user count is guarded by disabling interrupts on the running core only.
+/// On dual core MCX N each core image has its own count, so a peripheral must
+/// not be acquired from both cores, hand it to one core instead.
+pub trait PeripheralGate: PeripheralRST + PeripheralCC {
+    #[doc(hidden)]
+    fn users() -> &'static AtomicU8;
+
+    /// Take a reference to the peripheral, return `true` if this is the first user.
+    fn acquire(&mut self) -> bool {
+        let first = cortex_m::interrupt::free(|_| {
+            let users = Self::users().load(Ordering::Relaxed);
+            Self::users().store(users.saturating_add(1), Ordering::Relaxed);
+            users == 0
+        });
+        if first {
+            self.reset();
+            self.enable_clock(true);
+        }
+        first
+    }
+
+    /// Drop a reference to the peripheral, return `true` if this was the last user.
+    fn release(&mut self) -> bool {
+        let last = cortex_m::interrupt::free(|_| {
+            let users = Self::users().load(Ordering::Relaxed);
+            Self::users().store(users.saturating_sub(1), Ordering::Relaxed);
+            users == 1
+        });
+        if last {
+            self.enable_clock(false);
+        }
+        last
+    }
+
+    /// Return how many users hold the peripheral.
+    fn users_count(&self) -> u8 {
+        Self::users().load(Ordering::Relaxed)
+    }
+}
+
 /// Peripheral enable trait.
 pub trait PeripheralEn: Sealed {
     /// Enable or disable the peripheral.
@@ -31,8 +88,50 @@ pub trait PeripheralEn: Sealed {
 #[cfg_attr(feature = "mcxa0", path = "device/a0.rs")]
 #[cfg_attr(feature = "mcxa1", path = "device/a1.rs")]
 #[cfg_attr(feature = "mcxa2", path = "device/a2.rs")]
+#[cfg_attr(feature = "mcxn0", path = "device/n0.rs")]
+#[cfg_attr(feature = "mcxn1", path = "device/n1.rs")]
 mod device;
 pub use device::*;
 
+mod gate;
+use gate::periph_gate;
+
+#[cfg(feature = "mcxa")]
 mod mrcc;
+#[cfg(feature = "mcxa")]
 use mrcc::periph_mrcc;
+
+#[cfg(feature = "mcxn")]
+mod ahbclk;
+#[cfg(feature = "mcxn")]
+use ahbclk::{flexcomm_lpuart, periph_ahbclk};
+
+#[cfg(feature = "mcxa")]
+mod mrcc_divider;
+#[cfg(feature = "mcxa")]
+use mrcc_divider::{generate_mrcc_clock_source, generate_mrcc_divider};
+#[cfg(feature = "mcxa")]
+pub use mrcc_divider::{setup_clkout_clock_source, setup_clkout_divider, ClkOutSource};
+
+mod syscon_divider;
+pub use syscon_divider::*;
+
+/// Freeze or unfreeze clock configuration registers.
+///
+/// While frozen, writes to clock select and divider registers are ignored.
+/// This guards against accidental writes only, any code can unfreeze it again.
+/// MCX has no write-once clock or reset lock, keeping MRCC/SYSCON away from
+/// later code requires restricting their access with TRDC.
+pub fn freeze_clock_config(freeze: bool) {
+    let reg = unsafe { crate::pac::syscon::SYSCON0::instance().regs().CLKUNLOCK() };
+    // despite its name, UNLOCK set means the configuration is frozen
+    reg.write(|r| r.set_UNLOCK(freeze));
+}
+
+/// Return `true` if clock configuration registers are frozen.
+pub fn clock_config_frozen() -> bool {
+    unsafe { crate::pac::syscon::SYSCON0::instance().regs() }
+        .CLKUNLOCK()
+        .read()
+        .UNLOCK()
+}
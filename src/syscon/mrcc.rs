@@ -13,6 +13,10 @@ macro_rules! periph_mrcc {
         periph_mrcc!(impl_rst $virt, $n, $bit $(,hRST: $hRST)?);
         $(#[$features])?
         periph_mrcc!(impl_cc  $virt, $n, $bit $(,hCC: $hCC)?);
+        $(#[$features])?
+        periph_mrcc!(impl_gate $virt $(,hRST: $hRST)? $(,hCC: $hCC)?);
+        $(#[$features])?
+        periph_mrcc!(impl_acc $virt, $n, $bit $(,hACC: $hACC)?);
     };
 
     (periph: $periph:ty, $n:expr, $bit:expr $(, hRST: $hRST:expr)? $(, hCC: $hCC:expr)? $(, hACC: $hACC:expr)? $(, features: $features:meta)? ) => {
@@ -23,6 +27,10 @@ macro_rules! periph_mrcc {
         periph_mrcc!(impl_rst $periph, $n, $bit $(,hRST: $hRST)?);
         $(#[$features])?
         periph_mrcc!(impl_cc  $periph, $n, $bit $(,hCC: $hCC)?);
+        $(#[$features])?
+        periph_mrcc!(impl_gate $periph $(,hRST: $hRST)? $(,hCC: $hCC)?);
+        $(#[$features])?
+        periph_mrcc!(impl_acc $periph, $n, $bit $(,hACC: $hACC)?);
     };
 
     (impl_rst $name:ty, $n:expr, $bit:expr) => {};
@@ -39,6 +47,10 @@ macro_rules! periph_mrcc {
             }
         }
     };
+    (impl_gate $name:ty, hRST: $hRST:expr, hCC: $hCC:expr) => {
+        crate::syscon::periph_gate!($name);
+    };
+    (impl_gate $name:ty $(, $flag:ident: $value:expr)*) => {};
     (impl_cc $name:ty, $n:expr, $bit:expr) => {};
     (impl_cc $name:ty, $n:expr, $bit:expr, hCC: $hCC:expr) => {
         impl crate::syscon::PeripheralCC for $name {
@@ -53,10 +65,25 @@ macro_rules! periph_mrcc {
             }
         }
     };
-    // (impl_acc $name:ty, $n:expr, $bit:expr) => {};
-    // (impl_acc $name:ty, $n:expr, $bit:expr, hACC: $hACC:expr) => {
-
-    // };
+    (impl_acc $name:ty, $n:expr, $bit:expr) => {};
+    (impl_acc $name:ty, $n:expr, $bit:expr, hACC: $hACC:expr) => {
+        impl crate::syscon::PeripheralACC for $name {
+            #[inline(always)]
+            fn enable_auto_clock_gating(&mut self, enable: bool) {
+                let reg = unsafe {
+                    let ptr = crate::pac::mrcc::ADDRESSES[0] as *mut u8;
+                    crate::pac::common::Reg::<u32, crate::pac::common::RW>::from_ptr(ptr.add(0x80usize + $n * 0x04usize) as _)
+                };
+                reg.modify(|r| {
+                    if enable {
+                        *r |= 1 << $bit;
+                    } else {
+                        *r &= !(1 << $bit);
+                    }
+                });
+            }
+        }
+    };
 }
 
 pub(crate) use periph_mrcc;
@@ -40,6 +40,7 @@ pub enum ClkOutSource {
     FroHfDiv = 1,
     ClkIn = 2,
     Clk16K = 3,
+    #[cfg(feature = "mcxa2")]
     SPll = 5,
     SlowClk = 6,
     NoClock = 7,
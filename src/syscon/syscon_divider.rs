@@ -1,3 +1,4 @@
+#[cfg(any(feature = "mcxa2", feature = "mcxn"))]
 macro_rules! generate_syscon_divider {
     ($name: ident, $reg_name: ident, $comment:expr) => {
         #[doc = $comment]
@@ -30,11 +31,13 @@ pub fn setup_ahbclk_divider(divider: u8) {
     while reg.read().UNSTAB() {}
 }
 
+#[cfg(feature = "mcxa2")]
 generate_syscon_divider!(
     setup_fro_lf_divider,
     FROLFDIV,
     "Setup FRO_LF divider.\nThis clock is divided from SIRC_12M_CLK."
 );
+#[cfg(feature = "mcxa2")]
 generate_syscon_divider!(
     setup_fro_hf_divider,
     FROHFDIV,
@@ -47,3 +50,55 @@ generate_syscon_divider!(
     PLL1CLKDIV,
     "Setup SPLL_DIV divider.\nThis clock is divided from SPLL."
 );
+
+/// Setup FRO_HF divider.
+/// This clock is divided from FIRC_FCLK.
+#[cfg(feature = "mcxn")]
+pub fn setup_fro_hf_divider(divider: Option<u8>) {
+    // FROHFDIV has no RESET field on MCX N
+    let reg = unsafe { crate::pac::syscon::SYSCON0::instance().regs().FROHFDIV() };
+    match divider {
+        Some(divider) => {
+            reg.write(|r| {
+                r.set_DIV(divider);
+                r.set_HALT(false);
+            });
+            while reg.read().UNSTAB() {}
+        }
+        None => reg.write(|r| {
+            r.set_DIV(0);
+            r.set_HALT(true);
+        }),
+    }
+}
+
+#[cfg(feature = "mcxn")]
+generate_syscon_divider!(
+    setup_spll_clk_divider,
+    PLL1CLK0DIV,
+    "Setup PLL1_CLK0 divider.\nThis clock is divided from SPLL."
+);
+
+#[cfg(feature = "mcxn")]
+#[derive(Clone, Copy)]
+pub enum ClkOutSource {
+    MainClk = 0,
+    APll = 1,
+    ClkIn = 2,
+    FroHf = 3,
+    Fro12M = 4,
+    SPll = 5,
+    LpOsc = 6,
+    UPll = 7,
+    NoClock = 15,
+}
+
+/// Setup CLKOUT clock source.
+#[cfg(feature = "mcxn")]
+pub fn setup_clkout_clock_source(clock: ClkOutSource) {
+    let reg = unsafe { crate::pac::syscon::SYSCON0::instance().regs().CLKOUTSEL() };
+    reg.write(|r| r.set_SEL(clock as u8));
+}
+
+#[cfg(feature = "mcxn")]
+generate_syscon_divider!(setup_clkout_divider, CLKOUTDIV, "Setup CLKOUT divider.");